@@ -1,7 +1,6 @@
-use anyhow::Result;
 use ratatui::Frame;
 
-use crate::dmi::DMI;
+use crate::{compare::Compare, dmi::DMI, fleet::Fleet};
 
 #[non_exhaustive]
 #[derive(Debug)]
@@ -12,17 +11,45 @@ pub enum ActivePopup {
 #[derive(Debug)]
 pub struct App {
     pub running: bool,
-    pub dmi: DMI,
+    pub dmi: Option<DMI>,
+    pub compare: Option<Compare>,
+    pub fleet: Option<Fleet>,
 }
 
 impl App {
-    pub fn new() -> Result<Self> {
-        let dmi = DMI::new()?;
-        Ok(Self { running: true, dmi })
+    pub fn new(dmi: DMI, compare: Option<Compare>) -> Self {
+        Self {
+            running: true,
+            dmi: Some(dmi),
+            compare,
+            fleet: None,
+        }
+    }
+
+    // Starts on the fleet overview, with no host open.
+    pub fn with_fleet(fleet: Fleet) -> Self {
+        Self {
+            running: true,
+            dmi: None,
+            compare: None,
+            fleet: Some(fleet),
+        }
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
-        self.dmi.render(frame);
+        if let Some(compare) = &self.compare {
+            compare.render(frame);
+        } else if let Some(dmi) = &mut self.dmi {
+            dmi.render(frame);
+        } else if let Some(fleet) = &self.fleet {
+            fleet.render(frame);
+        }
+    }
+
+    // Whether keys are being typed into a text field rather than used as
+    // shortcuts.
+    pub fn is_editing(&self) -> bool {
+        self.compare.is_none() && self.dmi.as_ref().is_some_and(DMI::is_editing)
     }
 
     pub fn quit(&mut self) {
@@ -0,0 +1,276 @@
+// Hardware policy checks.
+//
+// A policy is a TOML file with one `[[rule]]` table per requirement. A rule
+// names a field path (see `query`) and the conditions every value it matches
+// must meet:
+//
+//   [[rule]]
+//   name = "ECC enabled"
+//   path = "memory.array.error_correction"
+//   contains = "ECC"
+//
+// With `each`, the path is relative to every element of a list, `when`
+// restricts the elements the rule applies to, and `equals_field` compares two
+// fields of the same element:
+//
+//   [[rule]]
+//   name = "DIMMs run at their rated speed"
+//   each = "memory.devices[*]"
+//   when = { path = "size_mb", min = 1 }
+//   path = "configured_speed"
+//   equals_field = "speed"
+
+use std::{cmp::Ordering, fmt, fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::query::{get, to_text};
+
+#[derive(Debug, Deserialize)]
+pub struct Policy {
+    #[serde(rename = "rule", default)]
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Rule {
+    name: Option<String>,
+    each: Option<String>,
+    when: Option<Test>,
+    #[serde(flatten)]
+    test: Test,
+}
+
+#[derive(Debug, Deserialize)]
+struct Test {
+    path: String,
+    equals: Option<Value>,
+    not_equals: Option<Value>,
+    contains: Option<String>,
+    one_of: Option<Vec<Value>>,
+    min: Option<f64>,
+    max: Option<f64>,
+    min_version: Option<String>,
+    #[serde(default)]
+    same: bool,
+    equals_field: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub name: String,
+    pub failure: Option<String>,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.failure {
+            None => write!(f, "PASS  {}", self.name),
+            Some(reason) => write!(f, "FAIL  {}: {reason}", self.name),
+        }
+    }
+}
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("{}", path.display()))?;
+        let policy: Self = toml::from_str(&text).with_context(|| format!("{}", path.display()))?;
+
+        if policy.rules.is_empty() {
+            bail!("{}: no [[rule]] found", path.display());
+        }
+        for rule in &policy.rules {
+            for test in std::iter::once(&rule.test).chain(&rule.when) {
+                if !test.has_condition() {
+                    bail!("{}: no condition on `{}`", path.display(), test.path);
+                }
+            }
+        }
+
+        Ok(policy)
+    }
+
+    pub fn check(&self, model: &Value) -> Vec<Outcome> {
+        self.rules
+            .iter()
+            .map(|rule| Outcome {
+                name: rule.name(),
+                failure: rule.check(model).err(),
+            })
+            .collect()
+    }
+}
+
+impl Rule {
+    fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| self.test.path.clone())
+    }
+
+    fn check(&self, model: &Value) -> Result<(), String> {
+        let scopes = match &self.each {
+            Some(each) => {
+                let mut scopes = Vec::new();
+                for element in get(model, each).map_err(|e| e.to_string())? {
+                    match &self.when {
+                        Some(when) if when.check(&[element]).is_err() => {}
+                        _ => scopes.push(element),
+                    }
+                }
+                scopes
+            }
+            None => vec![model],
+        };
+        self.test.check(&scopes)
+    }
+}
+
+impl Test {
+    fn has_condition(&self) -> bool {
+        self.equals.is_some()
+            || self.not_equals.is_some()
+            || self.contains.is_some()
+            || self.one_of.is_some()
+            || self.min.is_some()
+            || self.max.is_some()
+            || self.min_version.is_some()
+            || self.same
+            || self.equals_field.is_some()
+    }
+
+    // Every non-null value at `path` in each scope, paired with the value of
+    // `equals_field` in the same scope.
+    fn check(&self, scopes: &[&Value]) -> Result<(), String> {
+        let mut values = Vec::new();
+        for scope in scopes {
+            let found = get(scope, &self.path).map_err(|e| e.to_string())?;
+            let other = match &self.equals_field {
+                Some(field) => {
+                    let other = get(scope, field).map_err(|e| e.to_string())?;
+                    Some(other.first().copied().unwrap_or(&Value::Null))
+                }
+                None => None,
+            };
+            values.extend(
+                found
+                    .into_iter()
+                    .filter(|v| !v.is_null())
+                    .map(|v| (v, other)),
+            );
+        }
+
+        if values.is_empty() {
+            return Err(format!("no value at `{}`", self.path));
+        }
+
+        for (value, other) in &values {
+            self.check_value(value, *other)?;
+        }
+
+        if self.same
+            && let Some((first, _)) = values.first()
+            && let Some((value, _)) = values.iter().find(|(v, _)| !equal(v, first))
+        {
+            return Err(format!(
+                "`{}` differs: {} and {}",
+                self.path,
+                quote(first),
+                quote(value)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_value(&self, value: &Value, other: Option<&Value>) -> Result<(), String> {
+        let path = &self.path;
+        let is = format!("`{path}` is {}", quote(value));
+
+        if let Some(expected) = &self.equals
+            && !equal(value, expected)
+        {
+            return Err(format!("{is}, expected {}", quote(expected)));
+        }
+        if let Some(unwanted) = &self.not_equals
+            && equal(value, unwanted)
+        {
+            return Err(is);
+        }
+        if let Some(needle) = &self.contains
+            && !to_text(value).contains(needle.as_str())
+        {
+            return Err(format!("{is}, expected it to contain {needle:?}"));
+        }
+        if let Some(allowed) = &self.one_of
+            && !allowed.iter().any(|a| equal(value, a))
+        {
+            let allowed: Vec<String> = allowed.iter().map(quote).collect();
+            return Err(format!("{is}, expected one of {}", allowed.join(", ")));
+        }
+        if self.min.is_some() || self.max.is_some() {
+            let Some(number) = value.as_f64() else {
+                return Err(format!("{is}, expected a number"));
+            };
+            if let Some(min) = self.min
+                && number < min
+            {
+                return Err(format!("{is}, expected at least {min}"));
+            }
+            if let Some(max) = self.max
+                && number > max
+            {
+                return Err(format!("{is}, expected at most {max}"));
+            }
+        }
+        if let Some(min) = &self.min_version
+            && compare_versions(&to_text(value), min) == Ordering::Less
+        {
+            return Err(format!("{is}, expected {min} or later"));
+        }
+        if let (Some(field), Some(other)) = (&self.equals_field, other)
+            && !equal(value, other)
+        {
+            return Err(format!("{is}, `{field}` is {}", quote(other)));
+        }
+
+        Ok(())
+    }
+}
+
+// Numbers compare by value, so a policy's `3200` matches a decoded 3200 and
+// `1.0` matches 1.
+fn equal(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x == y,
+        _ => a == b,
+    }
+}
+
+fn quote(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("{s:?}"),
+        v => v.to_string(),
+    }
+}
+
+// Compares the numeric components of two version strings, so "1.10.2" is
+// later than "1.9" and "2.3a" equals "2.3".
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |v: &str| -> Vec<u64> {
+        v.split(|c: char| !c.is_ascii_digit())
+            .filter(|p| !p.is_empty())
+            .filter_map(|p| p.parse().ok())
+            .collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        if x != y {
+            return x.cmp(&y);
+        }
+    }
+    Ordering::Equal
+}
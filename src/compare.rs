@@ -0,0 +1,393 @@
+// Side by side view of two tables, one tab per section.
+//
+// Both models are flattened into rows of (field, left, right). Processors,
+// memory devices and slots are matched by socket, locator and designation,
+// so the rows of a device line up even when the two tables list them in a
+// different order or one of them has extra devices. Memory devices sharing a
+// locator are told apart by their bank, then by their order.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Margin},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Padding, Row, Table},
+};
+use serde_json::Value;
+
+use crate::{
+    diff::match_items,
+    dmi::{mask, mask_suffix},
+    query::{humanize, to_text},
+};
+
+const MISSING: &str = "—";
+
+#[derive(Debug)]
+enum Entry {
+    Heading(String, usize),
+    Field {
+        label: String,
+        depth: usize,
+        left: String,
+        right: String,
+        // How the values are masked when redacting, for identifying fields.
+        mask: Option<fn(&str, bool) -> String>,
+    },
+}
+
+impl Entry {
+    fn differs(&self) -> bool {
+        match self {
+            Self::Heading(..) => false,
+            Self::Field { left, right, .. } => left != right,
+        }
+    }
+
+    fn masked_by(mut self, key: &str) -> Self {
+        if let Self::Field { mask: m, .. } = &mut self {
+            *m = match key {
+                "serial_number" | "uuid" | "asset_tag" | "asset_tag_number" => Some(mask),
+                "part_number" => Some(mask_suffix),
+                _ => None,
+            };
+        }
+        self
+    }
+}
+
+#[derive(Debug)]
+struct Section {
+    title: String,
+    entries: Vec<Entry>,
+}
+
+impl Section {
+    fn differences(&self) -> usize {
+        self.entries.iter().filter(|e| e.differs()).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct Compare {
+    left_name: String,
+    right_name: String,
+    sections: Vec<Section>,
+    focused: usize,
+    offset: usize,
+    only_differences: bool,
+    // Masks serial numbers, UUIDs and asset tags on screen, like the DMI view.
+    pub redact: bool,
+}
+
+impl Compare {
+    pub fn new(left_name: String, left: &Value, right_name: String, right: &Value) -> Self {
+        let keys = left
+            .as_object()
+            .into_iter()
+            .flat_map(|m| m.keys())
+            .chain(right.as_object().into_iter().flat_map(|m| m.keys()));
+
+        let mut sections: Vec<Section> = Vec::new();
+        for key in keys {
+            if sections.iter().any(|s| s.title == humanize(key))
+                || (left[key].is_null() && right[key].is_null())
+            {
+                continue;
+            }
+            let mut entries = Vec::new();
+            flatten(
+                &mut entries,
+                key,
+                0,
+                Some(&left[key]).filter(|v| !v.is_null()),
+                Some(&right[key]).filter(|v| !v.is_null()),
+            );
+            sections.push(Section {
+                title: humanize(key),
+                entries,
+            });
+        }
+
+        Self {
+            left_name,
+            right_name,
+            sections,
+            focused: 0,
+            offset: 0,
+            only_differences: false,
+            redact: false,
+        }
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        let count = self.sections.len().max(1);
+        match key_event.code {
+            KeyCode::Tab => {
+                self.focused = (self.focused + 1) % count;
+                self.offset = 0;
+            }
+            KeyCode::BackTab => {
+                self.focused = (self.focused + count - 1) % count;
+                self.offset = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.offset + 1 < self.visible().len() => {
+                self.offset += 1;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.offset = self.offset.saturating_sub(1);
+            }
+            KeyCode::Char('d') => {
+                self.only_differences = !self.only_differences;
+                self.offset = 0;
+            }
+            KeyCode::Char('r') => {
+                self.redact = !self.redact;
+            }
+            _ => {}
+        }
+    }
+
+    fn visible(&self) -> Vec<&Entry> {
+        let Some(section) = self.sections.get(self.focused) else {
+            return Vec::new();
+        };
+        if !self.only_differences {
+            return section.entries.iter().collect();
+        }
+
+        // Keep the heading of every device that has a difference.
+        let mut visible = Vec::new();
+        let mut heading = None;
+        for entry in &section.entries {
+            match entry {
+                Entry::Heading(..) => heading = Some(entry),
+                e if e.differs() => {
+                    if let Some(h) = heading.take() {
+                        visible.push(h);
+                    }
+                    visible.push(e);
+                }
+                _ => {}
+            }
+        }
+        visible
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)])
+            .split(frame.area());
+        let (section_block, help_block) = (chunks[0], chunks[1]);
+
+        let mut title_spans: Vec<Span<'_>> = self
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let label = match s.differences() {
+                    0 => format!("  {}  ", s.title),
+                    n => format!("  {} ({n})  ", s.title),
+                };
+                if i == self.focused {
+                    Span::styled(label, Style::new().bold().reversed())
+                } else if s.differences() > 0 {
+                    Span::from(label).yellow()
+                } else {
+                    Span::from(label).dim()
+                }
+            })
+            .collect();
+        // First, so that it stays visible when the tabs overflow the title.
+        if self.redact {
+            title_spans.insert(0, Span::from(" REDACTED ").bold().black().on_yellow());
+        }
+
+        frame.render_widget(
+            Block::default()
+                .title(Line::from(title_spans))
+                .title_alignment(Alignment::Left)
+                .padding(Padding::top(1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+            section_block,
+        );
+
+        let help = if self.only_differences {
+            "⇆ : Sections   ↑↓ : Scroll   d : Show all fields   r : Redact"
+        } else {
+            "⇆ : Sections   ↑↓ : Scroll   d : Only differences   r : Redact"
+        };
+        frame.render_widget(Line::from(help).centered().dim(), help_block);
+
+        let rows: Vec<Row<'_>> = self
+            .visible()
+            .into_iter()
+            .skip(self.offset)
+            .map(|entry| match entry {
+                Entry::Heading(title, depth) => Row::new(vec![
+                    Cell::from(format!("{}{title}", "  ".repeat(*depth)))
+                        .bold()
+                        .underlined(),
+                ]),
+                Entry::Field {
+                    label,
+                    depth,
+                    left,
+                    right,
+                    mask,
+                } => {
+                    let text = |value: &String| match mask {
+                        Some(mask) => mask(value, self.redact),
+                        None => value.clone(),
+                    };
+                    let row = Row::new(vec![
+                        Cell::from(format!("{}{label}", "  ".repeat(*depth))).bold(),
+                        Cell::from(text(left)),
+                        Cell::from(text(right)),
+                    ]);
+                    if entry.differs() {
+                        row.yellow().bold()
+                    } else {
+                        row
+                    }
+                }
+            })
+            .collect();
+
+        let header = Row::new(vec![
+            Cell::from(""),
+            Cell::from(self.left_name.clone()),
+            Cell::from(self.right_name.clone()),
+        ])
+        .bold()
+        .underlined()
+        .bottom_margin(1);
+
+        let widths = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(2)
+            .block(Block::new().padding(Padding::uniform(1)));
+
+        frame.render_widget(table, section_block.inner(Margin::new(2, 1)));
+    }
+}
+
+fn flatten(
+    entries: &mut Vec<Entry>,
+    path: &str,
+    depth: usize,
+    left: Option<&Value>,
+    right: Option<&Value>,
+) {
+    if object(left).is_some() || object(right).is_some() {
+        let mut keys: Vec<&String> = object(left).into_iter().flat_map(|m| m.keys()).collect();
+        for key in object(right).into_iter().flat_map(|m| m.keys()) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        for key in keys {
+            let l = left.and_then(|v| v.get(key.as_str()));
+            let r = right.and_then(|v| v.get(key.as_str()));
+            let child = format!("{path}.{key}");
+            let empty =
+                |v: Option<&Value>| v.is_none_or(|v| v.as_array().is_some_and(Vec::is_empty));
+            if empty(l) && empty(r) && [l, r].iter().any(|v| v.is_some_and(Value::is_array)) {
+                continue;
+            }
+            let nested = [l, r]
+                .iter()
+                .any(|v| v.is_some_and(|v| v.is_object() || v.is_array()));
+            if nested {
+                entries.push(Entry::Heading(humanize(key), depth));
+                flatten(entries, &child, depth + 1, l, r);
+            } else {
+                entries.push(
+                    field(humanize(key), depth, l, r, left.is_some(), right.is_some())
+                        .masked_by(key),
+                );
+            }
+        }
+        return;
+    }
+
+    if array(left).is_some() || array(right).is_some() {
+        let l = array(left).map(Vec::as_slice).unwrap_or_default();
+        let r = array(right).map(Vec::as_slice).unwrap_or_default();
+
+        match match_items(path, l, r) {
+            Some(pairs) => {
+                for (name, a, b) in pairs {
+                    entries.push(Entry::Heading(name, depth));
+                    flatten(entries, path, depth + 1, a, b);
+                }
+            }
+            None => {
+                for i in 0..l.len().max(r.len()) {
+                    let (a, b) = (l.get(i), r.get(i));
+                    if [a, b]
+                        .iter()
+                        .any(|v| v.is_some_and(|v| v.is_object() || v.is_array()))
+                    {
+                        entries.push(Entry::Heading(format!("#{}", i + 1), depth));
+                        flatten(entries, path, depth + 1, a, b);
+                    } else {
+                        let label = format!("#{}", i + 1);
+                        entries.push(field(label, depth, a, b, a.is_some(), b.is_some()));
+                    }
+                }
+            }
+        }
+        return;
+    }
+
+    entries.push(field(
+        String::new(),
+        depth,
+        left,
+        right,
+        left.is_some(),
+        right.is_some(),
+    ));
+}
+
+fn object(value: Option<&Value>) -> Option<&serde_json::Map<String, Value>> {
+    value.and_then(Value::as_object)
+}
+
+fn array(value: Option<&Value>) -> Option<&Vec<Value>> {
+    value.and_then(Value::as_array)
+}
+
+fn field(
+    label: String,
+    depth: usize,
+    left: Option<&Value>,
+    right: Option<&Value>,
+    left_present: bool,
+    right_present: bool,
+) -> Entry {
+    let text = |value: Option<&Value>, present: bool| match value {
+        _ if !present => MISSING.to_string(),
+        Some(Value::Bool(true)) => "Yes".to_string(),
+        Some(Value::Bool(false)) => "No".to_string(),
+        Some(v) => to_text(v),
+        None => String::new(),
+    };
+    Entry::Field {
+        label,
+        depth,
+        left: text(left, left_present),
+        right: text(right, right_present),
+        mask: None,
+    }
+}
@@ -0,0 +1,190 @@
+// Field by field comparison of two decoded models.
+//
+// Lists of devices are matched by what identifies them physically rather than
+// by position, so a DIMM moved to another socket shows up as one removed and
+// one added device instead of a change to every field.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::query::to_text;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added(String, Value),
+    Removed(String, Value),
+    Changed(String, Value, Value),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(path, value) => write!(f, "+ {path}{}", summary(value)),
+            Self::Removed(path, value) => write!(f, "- {path}{}", summary(value)),
+            Self::Changed(path, old, new) => {
+                write!(f, "~ {path}: {} -> {}", text(old), text(new))
+            }
+        }
+    }
+}
+
+// The fields that identify an element of the list at `path`, if any, most
+// specific last: a DIMM is named by its locator, within its bank.
+fn list_key(path: &str) -> Option<&'static [&'static str]> {
+    match path {
+        "processors" => Some(&["socket_designation"]),
+        "memory.devices" => Some(&["bank_locator", "device_locator"]),
+        "slots" => Some(&["designation"]),
+        _ => None,
+    }
+}
+
+// An element of the left list, of the right list or of both, with its name.
+pub type Pair<'a> = (String, Option<&'a Value>, Option<&'a Value>);
+
+/// Pairs up the elements of two lists at `path` that are the same device,
+/// with the name each pair goes by: left elements in order, then those only
+/// on the right. `None` for lists compared by position.
+pub fn match_items<'a>(path: &str, a: &'a [Value], b: &'a [Value]) -> Option<Vec<Pair<'a>>> {
+    let keys = list_key(path)?;
+    let (last, _) = keys.split_last()?;
+    let short = |v: &Value| to_text(&v[*last]);
+    let full = |v: &Value| {
+        keys.iter()
+            .map(|k| to_text(&v[*k]))
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+
+    // The locator alone is enough unless another device shares it, as two
+    // "DIMM 0" in different banks do. Devices still named alike, such as
+    // several "Not Specified", are told apart by their order.
+    let all: Vec<&Value> = a.iter().chain(b).collect();
+    let name = |v: &Value| {
+        if all
+            .iter()
+            .any(|o| short(o) == short(v) && full(o) != full(v))
+        {
+            full(v)
+        } else {
+            short(v)
+        }
+    };
+    let named = |items: &'a [Value]| {
+        let mut seen: Vec<String> = Vec::new();
+        items
+            .iter()
+            .map(|v| {
+                let name = name(v);
+                let count = seen.iter().filter(|s| **s == name).count();
+                seen.push(name.clone());
+                match count {
+                    0 => (name, v),
+                    n => (format!("{name} #{}", n + 1), v),
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut right = named(b);
+    let mut pairs: Vec<_> = named(a)
+        .into_iter()
+        .map(|(name, item)| {
+            let other = right
+                .iter()
+                .position(|(n, _)| *n == name)
+                .map(|i| right.remove(i).1);
+            (name, Some(item), other)
+        })
+        .collect();
+    pairs.extend(
+        right
+            .into_iter()
+            .map(|(name, item)| (name, None, Some(item))),
+    );
+    Some(pairs)
+}
+
+pub fn diff(old: &Value, new: &Value) -> Vec<Change> {
+    let mut changes = Vec::new();
+    compare(&mut changes, "", old, new);
+    changes
+}
+
+fn compare(changes: &mut Vec<Change>, path: &str, old: &Value, new: &Value) {
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, value) in a {
+                let child = join(path, key);
+                match b.get(key) {
+                    Some(other) => compare(changes, &child, value, other),
+                    None if !value.is_null() => changes.push(Change::Removed(child, value.clone())),
+                    None => {}
+                }
+            }
+            for (key, value) in b {
+                if !a.contains_key(key) && !value.is_null() {
+                    changes.push(Change::Added(join(path, key), value.clone()));
+                }
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => match match_items(path, a, b) {
+            Some(pairs) => {
+                for (name, item, other) in pairs {
+                    let child = format!("{path}[{name}]");
+                    match (item, other) {
+                        (Some(item), Some(other)) => compare(changes, &child, item, other),
+                        (Some(item), None) => changes.push(Change::Removed(child, item.clone())),
+                        (None, Some(other)) => changes.push(Change::Added(child, other.clone())),
+                        (None, None) => {}
+                    }
+                }
+            }
+            None => {
+                for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                    compare(changes, &format!("{path}[{i}]"), x, y);
+                }
+                for (i, x) in a.iter().enumerate().skip(b.len()) {
+                    changes.push(Change::Removed(format!("{path}[{i}]"), x.clone()));
+                }
+                for (i, y) in b.iter().enumerate().skip(a.len()) {
+                    changes.push(Change::Added(format!("{path}[{i}]"), y.clone()));
+                }
+            }
+        },
+        (Value::Null, Value::Null) => {}
+        (Value::Null, v) if v.is_object() || v.is_array() => {
+            changes.push(Change::Added(path.to_string(), v.clone()))
+        }
+        (v, Value::Null) if v.is_object() || v.is_array() => {
+            changes.push(Change::Removed(path.to_string(), v.clone()))
+        }
+        (a, b) if a != b => changes.push(Change::Changed(path.to_string(), a.clone(), b.clone())),
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "(none)".to_string(),
+        Value::String(s) => format!("{s:?}"),
+        v => v.to_string(),
+    }
+}
+
+// Whole sections and devices are summarized rather than printed in full.
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Object(_) | Value::Array(_) => String::new(),
+        v => format!(": {}", text(v)),
+    }
+}
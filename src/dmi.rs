@@ -1,28 +1,46 @@
+mod address_map;
 mod baseboard;
 mod battery;
+mod boot;
 mod cache;
 mod chassis;
+mod event_log;
 mod firmware;
+mod group;
 mod memory;
+mod memory_error;
+mod oem;
+mod onboard;
+mod pointing;
+mod port;
 mod processor;
+mod security;
 mod slot;
 mod system;
+pub mod table;
 
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Read},
-    path::Path,
-};
+use std::{collections::HashMap, path::Path};
 
-use anyhow::{Result, bail};
+use anyhow::Result;
+use serde::Serialize;
 
+use crate::dmi::address_map::AddressMap;
 use crate::dmi::baseboard::Baseboard;
-use crate::dmi::battery::Battery;
+use crate::dmi::battery::{Batteries, Battery};
+use crate::dmi::boot::{BootInformation, BootPower, PowerControls};
 use crate::dmi::cache::Cache;
 use crate::dmi::chassis::Chassis;
+use crate::dmi::event_log::{DEV_MEM, EventLog};
 use crate::dmi::firmware::Firmware;
+use crate::dmi::group::{Group, Groups, Target};
 use crate::dmi::memory::{Memory, MemoryDevice, PhysicalMemoryArray};
+use crate::dmi::memory_error::MemoryError;
+use crate::dmi::oem::OemStrings;
+use crate::dmi::onboard::{OnboardDevice, OnboardDevices};
+use crate::dmi::pointing::{PointingDevice, PointingDevices};
+use crate::dmi::port::{Port, Ports};
 use crate::dmi::processor::{Processor, Processors};
+use crate::dmi::security::{HardwareSecurity, Security, SystemReset};
 use crate::dmi::slot::{Slot, Slots};
 use crate::dmi::system::System;
 
@@ -35,7 +53,7 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Padding},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct DMI {
     firmware: Option<Firmware>,
     system: Option<System>,
@@ -43,9 +61,80 @@ pub struct DMI {
     chassis: Option<Chassis>,
     processors: Option<Processors>,
     memory: Option<Memory>,
+    ports: Option<Ports>,
     slots: Option<Slots>,
-    battery: Option<Battery>,
+    onboard_devices: Option<OnboardDevices>,
+    oem_strings: Option<OemStrings>,
+    group_associations: Option<Groups>,
+    event_log: Option<EventLog>,
+    pointing_devices: Option<PointingDevices>,
+    batteries: Option<Batteries>,
+    security: Option<Security>,
+    boot_power: Option<BootPower>,
+    // Index of each processor, memory device, port, slot, onboard device,
+    // pointing device and battery in its section's list, by handle.
+    #[serde(skip)]
+    positions: HashMap<u16, usize>,
+    #[serde(skip)]
     pub focused_section: FocusedSection,
+    // Masks serial numbers, UUIDs and asset tags on screen, for screenshots.
+    #[serde(skip)]
+    pub redact: bool,
+}
+
+// Implements `Serialize` for types whose model representation is their
+// human-readable `Display` output (enum names, sizes, releases, ...).
+macro_rules! serialize_display {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+        )+
+    };
+}
+pub(crate) use serialize_display;
+
+// Placeholder strings carry nothing to hide and stay readable when redacted.
+fn is_placeholder(value: &str) -> bool {
+    matches!(
+        value.trim(),
+        "" | "Not Specified"
+            | "Not Available"
+            | "Not Present"
+            | "Unknown"
+            | "None"
+            | "To Be Filled By O.E.M."
+            | "Default string"
+    )
+}
+
+// An identifying value as shown on screen: with `redact`, every letter and
+// digit is masked and separators are kept, so the shape stays recognizable.
+pub(crate) fn mask(value: &str, redact: bool) -> String {
+    if !redact || is_placeholder(value) {
+        return value.to_string();
+    }
+    value
+        .chars()
+        .map(|c| if c.is_alphanumeric() { '•' } else { c })
+        .collect()
+}
+
+// Like `mask`, but keeps the first half of a part number, which names the
+// model, and only masks the rest.
+pub(crate) fn mask_suffix(value: &str, redact: bool) -> String {
+    if !redact || is_placeholder(value) {
+        return value.to_string();
+    }
+    let keep = value.chars().count().div_ceil(2);
+    let (head, tail): (String, String) = (
+        value.chars().take(keep).collect(),
+        value.chars().skip(keep).collect(),
+    );
+    head + &mask(&tail, true)
 }
 
 #[non_exhaustive]
@@ -57,8 +146,38 @@ pub enum FocusedSection {
     Chassis,
     Processor,
     Memory,
+    Ports,
     Slots,
+    OnboardDevices,
+    OemStrings,
+    Groups,
+    EventLog,
+    PointingDevices,
     Battery,
+    Security,
+    BootPower,
+}
+
+// The section a structure type is shown in, for following group members.
+fn section_of(structure_type: u8) -> Option<FocusedSection> {
+    match structure_type {
+        0 | 13 => Some(FocusedSection::Firmware),
+        1 => Some(FocusedSection::System),
+        2 => Some(FocusedSection::Baseboard),
+        3 => Some(FocusedSection::Chassis),
+        4 | 7 => Some(FocusedSection::Processor),
+        16..=20 | 33 => Some(FocusedSection::Memory),
+        8 => Some(FocusedSection::Ports),
+        9 => Some(FocusedSection::Slots),
+        10 | 41 => Some(FocusedSection::OnboardDevices),
+        11 | 12 => Some(FocusedSection::OemStrings),
+        15 => Some(FocusedSection::EventLog),
+        21 => Some(FocusedSection::PointingDevices),
+        22 => Some(FocusedSection::Battery),
+        23 | 24 => Some(FocusedSection::Security),
+        25 | 32 => Some(FocusedSection::BootPower),
+        _ => None,
+    }
 }
 
 #[derive(Debug)]
@@ -77,11 +196,27 @@ impl From<[u8; 4]> for Header {
             3 => StructureType::Chassis,
             4 => StructureType::Processor,
             7 => StructureType::Cache,
+            8 => StructureType::PortConnector,
             9 => StructureType::SystemSlots,
+            10 => StructureType::OnboardDevices,
+            11 => StructureType::OemStrings,
+            12 => StructureType::SystemConfigurationOptions,
             13 => StructureType::FirmwareLanguage,
+            14 => StructureType::GroupAssociations,
+            15 => StructureType::SystemEventLog,
             16 => StructureType::PhysicalMemoryArray,
             17 => StructureType::MemoryDevice,
+            18 => StructureType::MemoryError32,
+            19 => StructureType::MemoryArrayMappedAddress,
+            20 => StructureType::MemoryDeviceMappedAddress,
+            21 => StructureType::PointingDevice,
             22 => StructureType::Battery,
+            23 => StructureType::SystemReset,
+            24 => StructureType::HardwareSecurity,
+            25 => StructureType::SystemPowerControls,
+            32 => StructureType::SystemBootInformation,
+            33 => StructureType::MemoryError64,
+            41 => StructureType::OnboardDevicesExtended,
             127 => StructureType::End,
             _ => StructureType::Other,
         };
@@ -103,11 +238,27 @@ pub enum StructureType {
     Chassis = 3,
     Processor = 4,
     Cache = 7,
+    PortConnector = 8,
     SystemSlots = 9,
+    OnboardDevices = 10,
+    OemStrings = 11,
+    SystemConfigurationOptions = 12,
     FirmwareLanguage = 13,
+    GroupAssociations = 14,
+    SystemEventLog = 15,
     PhysicalMemoryArray = 16,
     MemoryDevice = 17,
+    MemoryError32 = 18,
+    MemoryArrayMappedAddress = 19,
+    MemoryDeviceMappedAddress = 20,
+    PointingDevice = 21,
     Battery = 22,
+    SystemReset = 23,
+    HardwareSecurity = 24,
+    SystemPowerControls = 25,
+    SystemBootInformation = 32,
+    MemoryError64 = 33,
+    OnboardDevicesExtended = 41,
     End = 127,
     Other = 255,
 }
@@ -116,6 +267,10 @@ pub enum StructureType {
 // https://www.dmtf.org/sites/default/files/standards/documents/DSP0134_3.8.0.pdf
 impl DMI {
     pub fn new() -> Result<Self> {
+        Self::from_table(&table::read()?)
+    }
+
+    pub fn from_table(table: &[u8]) -> Result<Self> {
         let mut firmware: Option<Firmware> = None;
         let mut system: Option<System> = None;
         let mut baseboard: Option<Baseboard> = None;
@@ -124,59 +279,33 @@ impl DMI {
         let mut caches: Vec<Cache> = Vec::new();
         let mut physical_memory_array: Option<PhysicalMemoryArray> = None;
         let mut memory_devices: Vec<MemoryDevice> = Vec::new();
+        let mut memory_errors: Vec<MemoryError> = Vec::new();
+        let mut address_map = AddressMap::default();
+        let mut port_list: Vec<Port> = Vec::new();
         let mut slot_list: Vec<Slot> = Vec::new();
-        let mut battery: Option<Battery> = None;
-
-        let dmi_file_path = Path::new("/sys/firmware/dmi/tables/DMI");
-
-        match dmi_file_path.try_exists() {
-            Ok(true) => {}
-            Ok(false) | Err(_) => bail!("No SMBIOS found"),
-        }
-
-        let mem_file = File::open(dmi_file_path)?;
-        let mut file = BufReader::new(mem_file);
-
-        loop {
-            // Read header
-            let mut header_buffer: [u8; 4] = [0; 4];
-            file.read_exact(&mut header_buffer)?;
-            let header = Header::from(header_buffer);
+        let mut onboard_list: Vec<OnboardDevice> = Vec::new();
+        let mut oem_strings = OemStrings::default();
+        let mut group_list: Vec<Group> = Vec::new();
+        let mut event_log: Option<EventLog> = None;
+        let mut targets: HashMap<u16, Target> = HashMap::new();
+        let mut positions: HashMap<u16, usize> = HashMap::new();
+        let mut pointing_list: Vec<PointingDevice> = Vec::new();
+        let mut battery_list: Vec<Battery> = Vec::new();
+        let mut system_reset: Option<SystemReset> = None;
+        let mut hardware_security: Option<HardwareSecurity> = None;
+        let mut power_controls: Option<PowerControls> = None;
+        let mut boot_information: Option<BootInformation> = None;
+
+        for structure in table::parse(table)? {
+            let header = &structure.header;
             if header.structure_type == StructureType::End {
                 break;
             }
+            structure.check_length()?;
 
-            if header.length < 4 {
-                bail!("Header size < 4");
-            }
-
-            // Read structure
-            let mut data = vec![0; header.length.saturating_sub(4) as usize];
-            file.read_exact(&mut data)?;
-
-            // Read strings. The string-set ends with an extra NUL after the
-            // last string's terminator, so for a structure with no strings the
-            // formatted area is followed by two NUL bytes.
-            let mut text: Vec<String> = Vec::new();
-            let mut saw_leading_zero = false;
-
-            loop {
-                let mut string_buf = Vec::new();
-                match file.read_until(0, &mut string_buf)? {
-                    0 => break,
-                    1 => {
-                        // Empty entry (just the terminator byte).
-                        if !text.is_empty() || saw_leading_zero {
-                            break;
-                        }
-                        saw_leading_zero = true;
-                    }
-                    _ => {
-                        string_buf.pop();
-                        text.push(String::from_utf8_lossy(&string_buf).to_string());
-                    }
-                }
-            }
+            let data = structure.data().to_vec();
+            let text = structure.text;
+            targets.insert(header.handle, Target::new(structure.bytes[0], &data, &text));
 
             match header.structure_type {
                 StructureType::Firmware => {
@@ -192,14 +321,34 @@ impl DMI {
                     chassis = Some(Chassis::from((data, text)));
                 }
                 StructureType::Processor => {
+                    positions.insert(header.handle, processor_list.len());
                     processor_list.push(Processor::from((data, text)));
                 }
                 StructureType::Cache => {
                     caches.push(Cache::parse(header.handle, data));
                 }
+                StructureType::PortConnector => {
+                    positions.insert(header.handle, port_list.len());
+                    port_list.push(Port::from((data, text)));
+                }
                 StructureType::SystemSlots => {
+                    positions.insert(header.handle, slot_list.len());
                     slot_list.push(Slot::from((data, text)));
                 }
+                StructureType::OnboardDevices => {
+                    positions.insert(header.handle, onboard_list.len());
+                    onboard_list.extend(OnboardDevice::parse_legacy(&data, &text));
+                }
+                StructureType::OnboardDevicesExtended => {
+                    positions.insert(header.handle, onboard_list.len());
+                    onboard_list.push(OnboardDevice::from((data, text)));
+                }
+                StructureType::OemStrings => {
+                    oem_strings.push_oem_strings(&data, &text);
+                }
+                StructureType::SystemConfigurationOptions => {
+                    oem_strings.push_configuration_options(&data, &text);
+                }
                 StructureType::FirmwareLanguage => {
                     let language_infos = firmware::LanguageInfos::from((data, text));
 
@@ -207,22 +356,79 @@ impl DMI {
                         firmware.language_infos = Some(language_infos);
                     }
                 }
+                StructureType::GroupAssociations => {
+                    group_list.push(Group::from((data, text)));
+                }
+                StructureType::SystemEventLog => {
+                    event_log = Some(EventLog::from((data, text)));
+                }
                 StructureType::PhysicalMemoryArray => {
                     physical_memory_array = Some(PhysicalMemoryArray::from(data.as_slice()));
                 }
                 StructureType::MemoryDevice => {
-                    memory_devices.push(MemoryDevice::from((data, text)));
+                    positions.insert(header.handle, memory_devices.len());
+                    memory_devices.push(MemoryDevice::from((data, text, header.handle)));
+                }
+                StructureType::MemoryError32 => {
+                    memory_errors.push(MemoryError::parse(header.handle, &data, false));
+                }
+                StructureType::MemoryArrayMappedAddress => {
+                    address_map.push_array(header.handle, &data);
+                }
+                StructureType::MemoryDeviceMappedAddress => {
+                    address_map.push_device(&data);
+                }
+                StructureType::MemoryError64 => {
+                    memory_errors.push(MemoryError::parse(header.handle, &data, true));
+                }
+                StructureType::PointingDevice => {
+                    positions.insert(header.handle, pointing_list.len());
+                    pointing_list.push(PointingDevice::from((data, text)));
                 }
                 StructureType::Battery => {
-                    battery = Some(Battery::from((data, text)));
+                    positions.insert(header.handle, battery_list.len());
+                    battery_list.push(Battery::from((data, text)));
+                }
+                StructureType::SystemReset => {
+                    system_reset = Some(SystemReset::from(data.as_slice()));
+                }
+                StructureType::HardwareSecurity => {
+                    hardware_security = Some(HardwareSecurity::from(data.as_slice()));
+                }
+                StructureType::SystemPowerControls => {
+                    power_controls = Some(PowerControls::from(data.as_slice()));
+                }
+                StructureType::SystemBootInformation => {
+                    boot_information = Some(BootInformation::from(data.as_slice()));
                 }
                 _ => {}
             }
         }
 
-        let memory = physical_memory_array.map(|pma| Memory::new(pma, memory_devices));
+        let memory = physical_memory_array
+            .map(|pma| Memory::new(pma, memory_devices, &memory_errors, address_map));
         let processors = Processors::new(processor_list, caches);
+        let ports = Ports::new(port_list);
         let slots = Slots::new(slot_list);
+        let onboard_devices = OnboardDevices::new(onboard_list);
+        let pointing_devices = PointingDevices::new(pointing_list);
+        let batteries = Batteries::new(battery_list);
+        let security = Security::new(
+            hardware_security,
+            system_reset,
+            chassis.as_ref().map(Chassis::security_status),
+        );
+        let boot_power = BootPower::new(boot_information, power_controls);
+        let oem_strings = (!oem_strings.is_empty()).then_some(oem_strings);
+        if let Some(event_log) = &mut event_log {
+            event_log.set_names(
+                targets
+                    .iter()
+                    .map(|(handle, target)| (*handle, target.to_string()))
+                    .collect(),
+            );
+        }
+        let group_associations = Groups::new(group_list, &targets);
 
         let focused_section = [
             (FocusedSection::Firmware, firmware.is_some()),
@@ -231,8 +437,16 @@ impl DMI {
             (FocusedSection::Chassis, chassis.is_some()),
             (FocusedSection::Processor, processors.is_some()),
             (FocusedSection::Memory, memory.is_some()),
+            (FocusedSection::Ports, ports.is_some()),
             (FocusedSection::Slots, slots.is_some()),
-            (FocusedSection::Battery, battery.is_some()),
+            (FocusedSection::OnboardDevices, onboard_devices.is_some()),
+            (FocusedSection::OemStrings, oem_strings.is_some()),
+            (FocusedSection::Groups, group_associations.is_some()),
+            (FocusedSection::EventLog, event_log.is_some()),
+            (FocusedSection::PointingDevices, pointing_devices.is_some()),
+            (FocusedSection::Battery, batteries.is_some()),
+            (FocusedSection::Security, security.is_some()),
+            (FocusedSection::BootPower, boot_power.is_some()),
         ]
         .into_iter()
         .find_map(|(s, present)| present.then_some(s))
@@ -245,14 +459,49 @@ impl DMI {
             chassis,
             processors,
             memory,
+            ports,
             slots,
-            battery,
+            onboard_devices,
+            oem_strings,
+            group_associations,
+            event_log,
+            pointing_devices,
+            batteries,
+            security,
+            boot_power,
+            positions,
             focused_section,
+            redact: false,
         })
     }
 
+    /// Resolves onboard devices to their network interfaces and drivers, and
+    /// batteries to their power supplies. Only call this for the table of
+    /// the running system.
+    pub fn link_sysfs(&mut self) {
+        if let Some(devices) = &mut self.onboard_devices {
+            devices.link_sysfs();
+        }
+        if let Some(batteries) = &mut self.batteries {
+            batteries.link_sysfs();
+        }
+    }
+
+    /// Reads the event log area from a memory image, or from /dev/mem when
+    /// there is none, which only makes sense for the running system.
+    pub fn read_event_log(&mut self, memory: Option<&Path>) {
+        if let Some(event_log) = &mut self.event_log {
+            event_log.read(memory.unwrap_or(Path::new(DEV_MEM)));
+        }
+    }
+
+    /// The decoded tables as a JSON tree, keyed by section then field name.
+    pub fn model(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("DMI model is always serializable")
+    }
+
     fn available_sections(&self) -> Vec<FocusedSection> {
-        let mut sections = Vec::with_capacity(8);
+        let mut sections = Vec::with_capacity(16);
         if self.firmware.is_some() {
             sections.push(FocusedSection::Firmware);
         }
@@ -271,28 +520,68 @@ impl DMI {
         if self.memory.is_some() {
             sections.push(FocusedSection::Memory);
         }
+        if self.ports.is_some() {
+            sections.push(FocusedSection::Ports);
+        }
         if self.slots.is_some() {
             sections.push(FocusedSection::Slots);
         }
-        if self.battery.is_some() {
+        if self.onboard_devices.is_some() {
+            sections.push(FocusedSection::OnboardDevices);
+        }
+        if self.oem_strings.is_some() {
+            sections.push(FocusedSection::OemStrings);
+        }
+        if self.group_associations.is_some() {
+            sections.push(FocusedSection::Groups);
+        }
+        if self.event_log.is_some() {
+            sections.push(FocusedSection::EventLog);
+        }
+        if self.pointing_devices.is_some() {
+            sections.push(FocusedSection::PointingDevices);
+        }
+        if self.batteries.is_some() {
             sections.push(FocusedSection::Battery);
         }
+        if self.security.is_some() {
+            sections.push(FocusedSection::Security);
+        }
+        if self.boot_power.is_some() {
+            sections.push(FocusedSection::BootPower);
+        }
         sections
     }
 
+    /// Whether a text field has focus, so single-letter shortcuts must not
+    /// fire.
+    pub fn is_editing(&self) -> bool {
+        match self.focused_section {
+            FocusedSection::OemStrings => self.oem_strings.as_ref().is_some_and(|o| o.editing),
+            FocusedSection::Memory => self.memory.as_ref().is_some_and(Memory::is_editing),
+            _ => false,
+        }
+    }
+
     pub fn handle_key_events(&mut self, key_event: KeyEvent) {
         let sections = self.available_sections();
         let Some(idx) = sections.iter().position(|s| *s == self.focused_section) else {
             return;
         };
 
+        // A text field takes every key until it is closed.
+        let editing = self.is_editing();
+
         match key_event.code {
-            KeyCode::Tab => {
+            KeyCode::Tab if !editing => {
                 self.focused_section = sections[(idx + 1) % sections.len()];
             }
-            KeyCode::BackTab => {
+            KeyCode::BackTab if !editing => {
                 self.focused_section = sections[(idx + sections.len() - 1) % sections.len()];
             }
+            KeyCode::Char('r') if !editing => {
+                self.redact = !self.redact;
+            }
             _ => match self.focused_section {
                 FocusedSection::Memory => {
                     if let Some(memory) = &mut self.memory {
@@ -304,16 +593,101 @@ impl DMI {
                         processors.handle_key_events(key_event);
                     }
                 }
+                FocusedSection::Ports => {
+                    if let Some(ports) = &mut self.ports {
+                        ports.handle_key_events(key_event);
+                    }
+                }
                 FocusedSection::Slots => {
                     if let Some(slots) = &mut self.slots {
                         slots.handle_key_events(key_event);
                     }
                 }
+                FocusedSection::OnboardDevices => {
+                    if let Some(devices) = &mut self.onboard_devices {
+                        devices.handle_key_events(key_event);
+                    }
+                }
+                FocusedSection::OemStrings => {
+                    if let Some(oem_strings) = &mut self.oem_strings {
+                        oem_strings.handle_key_events(key_event, self.redact);
+                    }
+                }
+                FocusedSection::PointingDevices => {
+                    if let Some(devices) = &mut self.pointing_devices {
+                        devices.handle_key_events(key_event);
+                    }
+                }
+                FocusedSection::Battery => {
+                    if let Some(batteries) = &mut self.batteries {
+                        batteries.handle_key_events(key_event);
+                    }
+                }
+                FocusedSection::EventLog => {
+                    if let Some(event_log) = &mut self.event_log {
+                        event_log.handle_key_events(key_event);
+                    }
+                }
+                FocusedSection::Groups => {
+                    // Enter follows the selected member to its section, and
+                    // selects it there.
+                    if let Some(groups) = &mut self.group_associations
+                        && let Some((structure_type, handle)) = groups.handle_key_events(key_event)
+                        && let Some(section) = section_of(structure_type)
+                        && sections.contains(&section)
+                    {
+                        self.focused_section = section;
+                        if let Some(&index) = self.positions.get(&handle) {
+                            self.select(section, index);
+                        }
+                    }
+                }
                 _ => {}
             },
         }
     }
 
+    fn select(&mut self, section: FocusedSection, index: usize) {
+        match section {
+            FocusedSection::Processor => {
+                if let Some(processors) = &mut self.processors {
+                    processors.select(index);
+                }
+            }
+            FocusedSection::Memory => {
+                if let Some(memory) = &mut self.memory {
+                    memory.select_device(index);
+                }
+            }
+            FocusedSection::Ports => {
+                if let Some(ports) = &mut self.ports {
+                    ports.select(index);
+                }
+            }
+            FocusedSection::Slots => {
+                if let Some(slots) = &mut self.slots {
+                    slots.select(index);
+                }
+            }
+            FocusedSection::OnboardDevices => {
+                if let Some(devices) = &mut self.onboard_devices {
+                    devices.select(index);
+                }
+            }
+            FocusedSection::PointingDevices => {
+                if let Some(devices) = &mut self.pointing_devices {
+                    devices.select(index);
+                }
+            }
+            FocusedSection::Battery => {
+                if let Some(batteries) = &mut self.batteries {
+                    batteries.select(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn title_span(&self, header_section: FocusedSection) -> Span<'_> {
         let label = match header_section {
             FocusedSection::Firmware => "  Firmware  ",
@@ -322,8 +696,16 @@ impl DMI {
             FocusedSection::Chassis => "  Chassis  ",
             FocusedSection::Processor => "  Processor  ",
             FocusedSection::Memory => "  Memory  ",
+            FocusedSection::Ports => "  Ports  ",
             FocusedSection::Slots => "  Slots  ",
+            FocusedSection::OnboardDevices => "  Onboard Devices  ",
+            FocusedSection::OemStrings => "  OEM Strings  ",
+            FocusedSection::Groups => "  Groups  ",
+            FocusedSection::EventLog => "  Event Log  ",
+            FocusedSection::PointingDevices => "  Pointing Devices  ",
             FocusedSection::Battery => "  Battery  ",
+            FocusedSection::Security => "  Security  ",
+            FocusedSection::BootPower => "  Boot & Power  ",
         };
 
         if self.focused_section == header_section {
@@ -344,12 +726,40 @@ impl DMI {
             (chunks[0], chunks[1])
         };
 
-        let title_spans: Vec<Span<'_>> = self
+        let mut title_spans: Vec<Span<'_>> = self
             .available_sections()
             .into_iter()
             .map(|s| self.title_span(s))
             .collect();
 
+        // When the tabs don't fit, drop the leading ones until the focused
+        // tab is visible, behind a 2 column "… " marker.
+        let mut room = (section_block.width as usize).saturating_sub(4);
+        if self.redact {
+            room = room.saturating_sub(" REDACTED ".len());
+        }
+        let focused = self
+            .available_sections()
+            .iter()
+            .position(|s| *s == self.focused_section)
+            .unwrap_or(0);
+        let width = |spans: &[Span<'_>]| spans.iter().map(Span::width).sum::<usize>();
+        let mut first = 0;
+        while first < focused && width(&title_spans[first..=focused]) > room {
+            if first == 0 {
+                room = room.saturating_sub(2);
+            }
+            first += 1;
+        }
+        title_spans.drain(..first);
+        if first > 0 {
+            title_spans.insert(0, Span::from("… ").dim());
+        }
+
+        if self.redact {
+            title_spans.push(Span::from(" REDACTED ").bold().black().on_yellow());
+        }
+
         frame.render_widget(
             Block::default()
                 .title(Line::from(title_spans))
@@ -370,15 +780,45 @@ impl DMI {
                 .processors
                 .as_ref()
                 .is_some_and(Processors::has_multiple),
+            FocusedSection::Ports => self.ports.as_ref().is_some_and(Ports::has_multiple),
             FocusedSection::Slots => self.slots.as_ref().is_some_and(Slots::has_multiple),
+            FocusedSection::OnboardDevices => self
+                .onboard_devices
+                .as_ref()
+                .is_some_and(OnboardDevices::has_multiple),
+            FocusedSection::Groups => self
+                .group_associations
+                .as_ref()
+                .is_some_and(Groups::has_multiple),
+            FocusedSection::EventLog => self.event_log.as_ref().is_some_and(EventLog::has_multiple),
+            FocusedSection::PointingDevices => self
+                .pointing_devices
+                .as_ref()
+                .is_some_and(PointingDevices::has_multiple),
+            FocusedSection::Battery => self.batteries.as_ref().is_some_and(Batteries::has_multiple),
             _ => false,
         };
-        let help_text = if inner_nav {
-            "⇆ : Sections   ↑↓ : Cycle"
+        let has_address_map = self.memory.as_ref().is_some_and(Memory::has_address_map);
+        let help_text = match self.focused_section {
+            _ if self.is_editing() => "⏎ : Done   Esc : Cancel",
+            FocusedSection::OemStrings => "⇆ : Sections   ↑↓ : Cycle   / : Search   c : Copy",
+            FocusedSection::Groups => "⇆ : Sections   ↑↓ : Cycle   ⏎ : Open",
+            FocusedSection::Memory if has_address_map => {
+                "⇆ : Sections   ↑↓ : Cycle   m : Address map   a : Look up address"
+            }
+            _ if inner_nav => "⇆ : Sections   ↑↓ : Cycle",
+            _ => "⇆ : Navigation",
+        };
+        let redact_text = if self.is_editing() {
+            ""
+        } else if self.redact {
+            "   r : Show identifiers"
         } else {
-            "⇆ : Navigation"
+            "   r : Redact"
         };
-        let message = Line::from(help_text).centered().dim();
+        let message = Line::from(format!("{help_text}{redact_text}"))
+            .centered()
+            .dim();
 
         frame.render_widget(message, help_block);
 
@@ -390,27 +830,32 @@ impl DMI {
             }
             FocusedSection::System => {
                 if let Some(system) = &self.system {
-                    system.render(frame, section_block);
+                    system.render(frame, section_block, self.redact);
                 }
             }
             FocusedSection::Baseboard => {
                 if let Some(baseboard) = &self.baseboard {
-                    baseboard.render(frame, section_block);
+                    baseboard.render(frame, section_block, self.redact);
                 }
             }
             FocusedSection::Chassis => {
                 if let Some(chassis) = &self.chassis {
-                    chassis.render(frame, section_block);
+                    chassis.render(frame, section_block, self.redact);
                 }
             }
             FocusedSection::Processor => {
                 if let Some(processors) = &mut self.processors {
-                    processors.render(frame, section_block);
+                    processors.render(frame, section_block, self.redact);
                 }
             }
             FocusedSection::Memory => {
                 if let Some(memory) = &mut self.memory {
-                    memory.render(frame, section_block);
+                    memory.render(frame, section_block, self.redact);
+                }
+            }
+            FocusedSection::Ports => {
+                if let Some(ports) = &mut self.ports {
+                    ports.render(frame, section_block);
                 }
             }
             FocusedSection::Slots => {
@@ -418,9 +863,44 @@ impl DMI {
                     slots.render(frame, section_block);
                 }
             }
+            FocusedSection::OnboardDevices => {
+                if let Some(devices) = &mut self.onboard_devices {
+                    devices.render(frame, section_block);
+                }
+            }
+            FocusedSection::OemStrings => {
+                if let Some(oem_strings) = &self.oem_strings {
+                    oem_strings.render(frame, section_block, self.redact);
+                }
+            }
+            FocusedSection::Groups => {
+                if let Some(groups) = &self.group_associations {
+                    groups.render(frame, section_block);
+                }
+            }
+            FocusedSection::EventLog => {
+                if let Some(event_log) = &self.event_log {
+                    event_log.render(frame, section_block);
+                }
+            }
+            FocusedSection::PointingDevices => {
+                if let Some(devices) = &mut self.pointing_devices {
+                    devices.render(frame, section_block);
+                }
+            }
             FocusedSection::Battery => {
-                if let Some(battery) = &self.battery {
-                    battery.render(frame, section_block);
+                if let Some(batteries) = &mut self.batteries {
+                    batteries.render(frame, section_block, self.redact);
+                }
+            }
+            FocusedSection::Security => {
+                if let Some(security) = &self.security {
+                    security.render(frame, section_block);
+                }
+            }
+            FocusedSection::BootPower => {
+                if let Some(boot_power) = &self.boot_power {
+                    boot_power.render(frame, section_block);
                 }
             }
         }
@@ -0,0 +1,259 @@
+// SMBIOS Type 19 (Memory Array Mapped Address) and Type 20 (Memory Device
+// Mapped Address). Spec reference: DSP0134 §7.20, §7.21.
+//
+// Together they tell which physical address ranges an array and each of its
+// devices answer to, which is what maps an MCE address back to a DIMM.
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    widgets::{Row, Table},
+};
+use serde::Serialize;
+
+#[derive(Debug, Default, Serialize)]
+pub struct AddressMap {
+    arrays: Vec<ArrayRange>,
+    devices: Vec<DeviceRange>,
+}
+
+#[derive(Debug, Serialize)]
+struct ArrayRange {
+    #[serde(skip)]
+    handle: u16,
+    start: u64,
+    end: u64,
+    // Number of devices that form a row.
+    partition_width: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct DeviceRange {
+    start: u64,
+    end: u64,
+    #[serde(skip)]
+    device_handle: u16,
+    #[serde(skip)]
+    array_range_handle: u16,
+    // Device locator, filled in by `AddressMap::resolve`.
+    device: Option<String>,
+    partition_row: Option<u8>,
+    interleave_position: Option<u8>,
+    interleaved_data_depth: Option<u8>,
+}
+
+// Start and inclusive end, in bytes. The 32-bit fields count kilobytes; when
+// the start is all ones, the 64-bit extended fields hold byte addresses.
+fn range(data: &[u8], extended: usize) -> (u64, u64) {
+    let start = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    let end = u32::from_le_bytes(data[4..8].try_into().unwrap());
+    if start == 0xFFFF_FFFF
+        && let Some(ext) = data.get(extended..extended + 16)
+    {
+        return (
+            u64::from_le_bytes(ext[0..8].try_into().unwrap()),
+            u64::from_le_bytes(ext[8..16].try_into().unwrap()),
+        );
+    }
+    (start as u64 * 1024, (end as u64 + 1) * 1024 - 1)
+}
+
+// 0xFF means unknown.
+fn known(value: u8) -> Option<u8> {
+    (value != 0xFF).then_some(value)
+}
+
+impl AddressMap {
+    pub fn push_array(&mut self, handle: u16, data: &[u8]) {
+        let (start, end) = range(data, 11);
+        self.arrays.push(ArrayRange {
+            handle,
+            start,
+            end,
+            partition_width: data[10],
+        });
+    }
+
+    pub fn push_device(&mut self, data: &[u8]) {
+        let (start, end) = range(data, 15);
+        self.devices.push(DeviceRange {
+            start,
+            end,
+            device_handle: u16::from_le_bytes([data[8], data[9]]),
+            array_range_handle: u16::from_le_bytes([data[10], data[11]]),
+            device: None,
+            partition_row: known(data[12]),
+            // 0 means the device is not interleaved.
+            interleave_position: known(data[13]).filter(|p| *p != 0),
+            interleaved_data_depth: known(data[14]).filter(|d| *d != 0),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arrays.is_empty() && self.devices.is_empty()
+    }
+
+    /// Names each device range after the locator of the device it maps.
+    pub fn resolve(&mut self, locators: &[(u16, String)]) {
+        for range in &mut self.devices {
+            range.device = locators
+                .iter()
+                .find(|(handle, _)| *handle == range.device_handle)
+                .map(|(_, locator)| locator.clone());
+        }
+        self.arrays.sort_by_key(|a| a.start);
+        self.devices.sort_by_key(|d| d.start);
+    }
+
+    /// Handles of the devices whose range holds `address`, with a sentence
+    /// describing the result.
+    pub fn lookup(&self, address: u64) -> (Vec<u16>, String) {
+        let matches: Vec<&DeviceRange> = self
+            .devices
+            .iter()
+            .filter(|d| (d.start..=d.end).contains(&address))
+            .collect();
+
+        if matches.is_empty() {
+            let message = match self
+                .arrays
+                .iter()
+                .find(|a| (a.start..=a.end).contains(&address))
+            {
+                Some(array) => format!(
+                    "{address:#x} is in array range {}, but no device range holds it",
+                    span(array.start, array.end)
+                ),
+                None => format!("{address:#x} is not mapped to memory"),
+            };
+            return (Vec::new(), message);
+        }
+
+        let handles: Vec<u16> = matches.iter().map(|d| d.device_handle).collect();
+        let described: Vec<String> = matches
+            .iter()
+            .map(|d| match d.interleave() {
+                Some(interleave) => format!("{} ({interleave})", d.name()),
+                None => d.name(),
+            })
+            .collect();
+        let message = if matches.len() > 1 {
+            // The interleave granularity is not in the table, so the range
+            // alone can't tell which of the interleaved devices holds it.
+            format!(
+                "{address:#x} → one of {}, interleaved",
+                described.join(", ")
+            )
+        } else {
+            format!("{address:#x} → {}", described[0])
+        };
+        (handles, message)
+    }
+
+    // Every array range followed by the device ranges within it. Ranges that
+    // hold `highlight` stand out.
+    pub fn render(&self, frame: &mut Frame, block: Rect, highlight: Option<u64>) {
+        let holds = |start: u64, end: u64| highlight.is_some_and(|a| (start..=end).contains(&a));
+        let style = |row: Row<'static>, start: u64, end: u64| {
+            if holds(start, end) {
+                row.style(Style::new().bold().reversed())
+            } else {
+                row
+            }
+        };
+
+        let mut rows = Vec::new();
+        for array in &self.arrays {
+            rows.push(style(
+                Row::new(vec![
+                    span(array.start, array.end),
+                    format_size(array.start, array.end),
+                    "Memory array".to_string(),
+                    format!("Partition width {}", array.partition_width),
+                ])
+                .bold(),
+                array.start,
+                array.end,
+            ));
+            for device in self
+                .devices
+                .iter()
+                .filter(|d| d.array_range_handle == array.handle)
+            {
+                rows.push(style(device.row("  "), device.start, device.end));
+            }
+        }
+        // Device ranges whose array range isn't in the table.
+        for device in self
+            .devices
+            .iter()
+            .filter(|d| !self.arrays.iter().any(|a| a.handle == d.array_range_handle))
+        {
+            rows.push(style(device.row(""), device.start, device.end));
+        }
+
+        let widths = [
+            Constraint::Length(35),
+            Constraint::Length(9),
+            Constraint::Length(16),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Range", "Size", "Mapped To", "Layout"])
+                    .bold()
+                    .bottom_margin(1),
+            )
+            .column_spacing(2);
+        frame.render_widget(table, block);
+    }
+}
+
+impl DeviceRange {
+    fn name(&self) -> String {
+        self.device
+            .clone()
+            .unwrap_or_else(|| format!("Handle {:#06x}", self.device_handle))
+    }
+
+    fn interleave(&self) -> Option<String> {
+        match (self.interleave_position, self.interleaved_data_depth) {
+            (Some(position), Some(depth)) => {
+                Some(format!("interleave position {position}, depth {depth}"))
+            }
+            (Some(position), None) => Some(format!("interleave position {position}")),
+            (None, Some(depth)) => Some(format!("interleave depth {depth}")),
+            (None, None) => None,
+        }
+    }
+
+    fn row(&self, indent: &str) -> Row<'static> {
+        let mut layout = Vec::new();
+        if let Some(row) = self.partition_row {
+            layout.push(format!("row {row}"));
+        }
+        layout.extend(self.interleave());
+
+        Row::new(vec![
+            format!("{indent}{}", span(self.start, self.end)),
+            format_size(self.start, self.end),
+            format!("{indent}{}", self.name()),
+            layout.join(", "),
+        ])
+    }
+}
+
+fn span(start: u64, end: u64) -> String {
+    format!("{start:#014x}–{end:#014x}")
+}
+
+fn format_size(start: u64, end: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = end.saturating_sub(start).saturating_add(1);
+    let mut unit = 0;
+    while value >= 1024 && value.is_multiple_of(1024) && unit < UNITS.len() - 1 {
+        value /= 1024;
+        unit += 1;
+    }
+    format!("{value} {}", UNITS[unit])
+}
@@ -6,8 +6,11 @@ use ratatui::{
     style::{Style, Stylize},
     widgets::{Block, Cell, List, Padding, Row, Table},
 };
+use serde::Serialize;
 
-#[derive(Debug)]
+use crate::dmi::{mask, serialize_display};
+
+#[derive(Debug, Serialize)]
 pub struct Baseboard {
     manufacturer: String,
     product: String,
@@ -15,6 +18,7 @@ pub struct Baseboard {
     serial_number: String,
     asset_tag: String,
     features: Vec<Feaures>,
+    #[serde(rename = "location_in_chassis")]
     loacation_in_chassis: String,
     board_type: BoardType,
 }
@@ -46,6 +50,8 @@ impl Display for Feaures {
     }
 }
 
+serialize_display!(Feaures, BoardType);
+
 #[derive(Debug)]
 enum BoardType {
     Unknown,
@@ -141,7 +147,7 @@ impl From<(Vec<u8>, Vec<String>)> for Baseboard {
 }
 
 impl Baseboard {
-    pub fn render(&self, frame: &mut Frame, block: Rect) {
+    pub fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
         let (infos_block, feaures_block) = {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -166,11 +172,11 @@ impl Baseboard {
             ]),
             Row::new(vec![
                 Cell::from("Serial Number").bold(),
-                Cell::from(self.serial_number.to_string()),
+                Cell::from(mask(&self.serial_number.to_string(), redact)),
             ]),
             Row::new(vec![
                 Cell::from("Asset Tag").bold(),
-                Cell::from(self.asset_tag.to_string()),
+                Cell::from(mask(&self.asset_tag.to_string(), redact)),
             ]),
             Row::new(vec![
                 Cell::from("Location in Chassis").bold(),
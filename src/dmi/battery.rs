@@ -1,11 +1,199 @@
+use std::{fs, path::Path};
+
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
-    layout::{Constraint, Margin, Rect},
-    style::Stylize,
-    widgets::{Block, Cell, Padding, Row, Table},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
 };
+use serde::{Serialize, Serializer};
+
+use crate::dmi::mask;
+
+const SYSFS_POWER_SUPPLY: &str = "/sys/class/power_supply";
 
 #[derive(Debug)]
+pub struct Batteries {
+    list: Vec<Battery>,
+    selected: usize,
+}
+
+impl Batteries {
+    pub fn new(list: Vec<Battery>) -> Option<Self> {
+        if list.is_empty() {
+            None
+        } else {
+            Some(Self { list, selected: 0 })
+        }
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.list.len() >= 2
+    }
+
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
+    /// Matches every battery to a `BAT*` power supply and reads its live
+    /// figures. Only meaningful when the table was read from the running
+    /// system.
+    pub fn link_sysfs(&mut self) {
+        let mut supplies: Vec<PowerSupply> = fs::read_dir(SYSFS_POWER_SUPPLY)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.file_name().to_string_lossy().starts_with("BAT"))
+                    .filter_map(|e| PowerSupply::read(&e.path()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        supplies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // A supply reporting the battery's serial number or name is the one.
+        // Otherwise a battery and a supply are only paired when they are the
+        // last of each: with more, a battery could show another pack's figures.
+        for battery in &mut self.list {
+            if let Some(i) = supplies.iter().position(|s| {
+                s.serial_number.as_deref() == Some(battery.serial_number.as_str())
+                    || s.model_name.as_deref() == Some(battery.device_name.as_str())
+            }) {
+                battery.live = Some(supplies.remove(i));
+            }
+        }
+        let mut unmatched: Vec<&mut Battery> =
+            self.list.iter_mut().filter(|b| b.live.is_none()).collect();
+        if let ([battery], [_]) = (unmatched.as_mut_slice(), supplies.as_slice()) {
+            battery.live = supplies.pop();
+        }
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        if !self.has_multiple() {
+            return;
+        }
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % self.list.len();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + self.list.len() - 1) % self.list.len();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, block: Rect, redact: bool) {
+        if !self.has_multiple() {
+            self.list[0].render(frame, block, redact);
+            return;
+        }
+
+        let max_label = self
+            .list
+            .iter()
+            .map(|b| b.device_name.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        // 4 = 2 borders + 2 horizontal padding
+        let list_width = max_label.saturating_add(4).max(14);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let items: Vec<ListItem<'_>> = self
+            .list
+            .iter()
+            .map(|b| ListItem::new(b.device_name.clone()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 1, 0)),
+            )
+            .highlight_style(Style::new().bold().reversed())
+            .highlight_symbol("");
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, body[0], &mut state);
+
+        if let Some(battery) = self.list.get(self.selected) {
+            battery.render(frame, body[1], redact);
+        }
+    }
+}
+
+impl Serialize for Batteries {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
+// Live state of a battery, from /sys/class/power_supply/BAT*. Capacities are
+// in mWh; supplies that report charge in µAh are converted with their design
+// voltage.
+#[derive(Debug, Serialize)]
+struct PowerSupply {
+    name: String,
+    status: Option<String>,
+    charge_percent: Option<u8>,
+    full_capacity: Option<u64>,
+    full_design_capacity: Option<u64>,
+    cycle_count: Option<u32>,
+    #[serde(skip)]
+    model_name: Option<String>,
+    #[serde(skip)]
+    serial_number: Option<String>,
+}
+
+impl PowerSupply {
+    fn read(path: &Path) -> Option<Self> {
+        let text = |name: &str| {
+            fs::read_to_string(path.join(name))
+                .ok()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+        let number = |name: &str| text(name).and_then(|s| s.parse::<u64>().ok());
+
+        // Only batteries, not the mains adapter or a USB-C source.
+        if text("type").as_deref() != Some("Battery") {
+            return None;
+        }
+
+        let voltage = number("voltage_min_design");
+        let capacity = |energy: &str, charge: &str| {
+            number(energy).map(|uwh| uwh / 1000).or_else(|| {
+                number(charge)
+                    .zip(voltage)
+                    .map(|(uah, uv)| uah * uv / 1_000_000_000)
+            })
+        };
+
+        Some(Self {
+            name: path.file_name()?.to_string_lossy().to_string(),
+            status: text("status"),
+            charge_percent: number("capacity").and_then(|c| u8::try_from(c).ok()),
+            full_capacity: capacity("energy_full", "charge_full"),
+            full_design_capacity: capacity("energy_full_design", "charge_full_design"),
+            cycle_count: number("cycle_count").and_then(|c| u32::try_from(c).ok()),
+            model_name: text("model_name"),
+            serial_number: text("serial_number"),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct Battery {
     location: String,
     manufacturer: String,
@@ -18,6 +206,8 @@ pub struct Battery {
     sbds_version: String,
     max_error_in_battery: Option<u8>,
     oem_specific: u32,
+    // Filled in from sysfs by `Batteries::link_sysfs`.
+    live: Option<PowerSupply>,
 }
 
 impl From<(Vec<u8>, Vec<String>)> for Battery {
@@ -84,13 +274,14 @@ impl From<(Vec<u8>, Vec<String>)> for Battery {
             sbds_version: text[data[10].saturating_sub(1) as usize].clone(),
             max_error_in_battery,
             oem_specific: u32::from_le_bytes(data[18..22].try_into().unwrap()),
+            live: None,
         }
     }
 }
 
 impl Battery {
-    pub fn render(&self, frame: &mut Frame, block: Rect) {
-        let rows = vec![
+    fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
+        let mut rows = vec![
             Row::new(vec![
                 Cell::from("location").bold(),
                 Cell::from(self.location.clone()),
@@ -105,7 +296,7 @@ impl Battery {
             ]),
             Row::new(vec![
                 Cell::from("Serial number").bold(),
-                Cell::from(self.serial_number.clone()),
+                Cell::from(mask(&self.serial_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Name").bold(),
@@ -154,11 +345,66 @@ impl Battery {
                 Cell::from(format!("0x{:X}", self.oem_specific)),
             ]),
         ];
+        if let Some(live) = &self.live {
+            rows.extend(self.live_rows(live));
+        }
 
         let widths = [Constraint::Length(20), Constraint::Fill(1)];
         let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
         frame.render_widget(table, block.inner(Margin::new(2, 0)));
     }
+
+    fn live_rows(&self, live: &PowerSupply) -> Vec<Row<'static>> {
+        let mut rows = vec![
+            Row::new(vec![
+                Cell::from("Power Supply").bold(),
+                Cell::from(live.name.clone()),
+            ])
+            .top_margin(1),
+        ];
+        if let Some(status) = &live.status {
+            rows.push(Row::new(vec![
+                Cell::from("Status").bold(),
+                Cell::from(status.clone()),
+            ]));
+        }
+        if let Some(percent) = live.charge_percent {
+            rows.push(Row::new(vec![
+                Cell::from("Charge").bold(),
+                Cell::from(format!("{percent}%")),
+            ]));
+        }
+        if let Some(full) = live.full_capacity {
+            // Wear is measured against the design capacity the battery
+            // reports itself, or the one in the table.
+            let design = live
+                .full_design_capacity
+                .or(self.design_capacity.map(u64::from))
+                .filter(|d| *d > 0);
+            let (text, worn) = match design {
+                Some(design) => {
+                    let health = full * 100 / design;
+                    (format!("{full} mWh ({health}% of design)"), health < 80)
+                }
+                None => (format!("{full} mWh"), false),
+            };
+            rows.push(Row::new(vec![
+                Cell::from("Full Capacity").bold(),
+                if worn {
+                    Cell::from(text).red().bold()
+                } else {
+                    Cell::from(text)
+                },
+            ]));
+        }
+        if let Some(cycles) = live.cycle_count {
+            rows.push(Row::new(vec![
+                Cell::from("Cycle Count").bold(),
+                Cell::from(cycles.to_string()),
+            ]));
+        }
+        rows
+    }
 }
 
 #[derive(Debug, strum::Display)]
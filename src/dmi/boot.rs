@@ -0,0 +1,242 @@
+// SMBIOS Type 25 (System Power Controls) and Type 32 (System Boot
+// Information). Spec reference: DSP0134 §7.26, §7.33.
+use ratatui::{
+    Frame,
+    layout::{Constraint, Margin, Rect},
+    style::Stylize,
+    widgets::{Block, Cell, Padding, Row, Table},
+};
+use serde::Serialize;
+
+use crate::dmi::serialize_display;
+
+#[derive(Debug, Serialize)]
+pub struct BootPower {
+    boot_information: Option<BootInformation>,
+    power_controls: Option<PowerControls>,
+}
+
+impl BootPower {
+    pub fn new(
+        boot_information: Option<BootInformation>,
+        power_controls: Option<PowerControls>,
+    ) -> Option<Self> {
+        if boot_information.is_none() && power_controls.is_none() {
+            return None;
+        }
+        Some(Self {
+            boot_information,
+            power_controls,
+        })
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect) {
+        let mut rows = Vec::new();
+        let heading = |title: &'static str| Row::new(vec![Cell::from(title).bold().underlined()]);
+
+        if let Some(boot) = &self.boot_information {
+            rows.push(heading("System Boot"));
+            let status = boot.status.to_string();
+            rows.push(Row::new(vec![
+                Cell::from("Boot Status").bold(),
+                if boot.status.is_failure() {
+                    Cell::from(status).red().bold()
+                } else {
+                    Cell::from(status)
+                },
+            ]));
+            if let Some(meaning) = boot.status.meaning() {
+                rows.push(Row::new(vec![Cell::from(""), Cell::from(meaning).dim()]));
+            }
+            if !boot.additional_data.is_empty() {
+                rows.push(Row::new(vec![
+                    Cell::from("Additional Data").bold(),
+                    Cell::from(boot.additional_data.clone()),
+                ]));
+            }
+            rows.push(Row::new(vec![""]));
+        }
+
+        if let Some(power) = &self.power_controls {
+            rows.push(heading("System Power Controls"));
+            rows.push(Row::new(vec![
+                Cell::from("Next Power-On").bold(),
+                Cell::from(power.next_power_on.clone()),
+            ]));
+            if power.next_power_on.contains('*') {
+                rows.push(Row::new(vec![
+                    Cell::from(""),
+                    Cell::from("Fields shown as * match any value").dim(),
+                ]));
+            }
+        }
+
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
+        frame.render_widget(table, block.inner(Margin::new(2, 0)));
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BootInformation {
+    status: BootStatus,
+    // Bytes that follow the status code, in hex. Their meaning depends on it.
+    additional_data: String,
+}
+
+impl From<&[u8]> for BootInformation {
+    fn from(data: &[u8]) -> Self {
+        // Six reserved bytes come before the status.
+        let status = data.get(6..).unwrap_or_default();
+        Self {
+            status: BootStatus::from(status.first().copied().unwrap_or(0)),
+            additional_data: status
+                .iter()
+                .skip(1)
+                .map(|b| format!("{b:02X}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+#[derive(Debug, strum::Display)]
+enum BootStatus {
+    #[strum(to_string = "No errors detected")]
+    NoErrors,
+    #[strum(to_string = "No bootable media")]
+    NoBootableMedia,
+    #[strum(to_string = "Operating system failed to load")]
+    OsFailedToLoad,
+    #[strum(to_string = "Firmware-detected hardware failure")]
+    FirmwareHardwareFailure,
+    #[strum(to_string = "Operating system-detected hardware failure")]
+    OsHardwareFailure,
+    #[strum(to_string = "User-requested boot")]
+    UserRequested,
+    #[strum(to_string = "System security violation")]
+    SecurityViolation,
+    #[strum(to_string = "Previously-requested image")]
+    PreviouslyRequestedImage,
+    #[strum(to_string = "System watchdog timer expired")]
+    WatchdogExpired,
+    #[strum(to_string = "Reserved ({0})")]
+    Reserved(u8),
+    #[strum(to_string = "Vendor/OEM-specific ({0})")]
+    Vendor(u8),
+    #[strum(to_string = "Product-specific ({0})")]
+    Product(u8),
+}
+
+impl From<u8> for BootStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoErrors,
+            1 => Self::NoBootableMedia,
+            2 => Self::OsFailedToLoad,
+            3 => Self::FirmwareHardwareFailure,
+            4 => Self::OsHardwareFailure,
+            5 => Self::UserRequested,
+            6 => Self::SecurityViolation,
+            7 => Self::PreviouslyRequestedImage,
+            8 => Self::WatchdogExpired,
+            9..=127 => Self::Reserved(value),
+            128..=191 => Self::Vendor(value),
+            192..=255 => Self::Product(value),
+        }
+    }
+}
+
+impl BootStatus {
+    // The previous boot went wrong.
+    fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::NoBootableMedia
+                | Self::OsFailedToLoad
+                | Self::FirmwareHardwareFailure
+                | Self::OsHardwareFailure
+                | Self::SecurityViolation
+                | Self::WatchdogExpired
+        )
+    }
+
+    fn meaning(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::NoErrors => return None,
+            Self::NoBootableMedia => "No device held an operating system to boot",
+            Self::OsFailedToLoad => {
+                "A boot loader was found, but the operating system did not start"
+            }
+            Self::FirmwareHardwareFailure => {
+                "The firmware found a hardware failure during POST, or one of unknown type"
+            }
+            Self::OsHardwareFailure => "The operating system reported a hardware failure",
+            Self::UserRequested => "The user asked for this boot, usually with a key press",
+            Self::SecurityViolation => "The firmware refused to boot because of a security check",
+            Self::PreviouslyRequestedImage => {
+                "The operating system asked for this image on the previous boot"
+            }
+            Self::WatchdogExpired => "The system was restarted by its watchdog timer",
+            Self::Reserved(_) => "Not assigned by the specification",
+            Self::Vendor(_) => "Codes 128-191 are defined by the system vendor or OEM",
+            Self::Product(_) => "Codes 192-255 are defined for the specific product",
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PowerControls {
+    // Month, day and time of the next scheduled power-on. The year is not
+    // part of the structure.
+    next_power_on: String,
+}
+
+impl From<&[u8]> for PowerControls {
+    fn from(data: &[u8]) -> Self {
+        // Each field is BCD; a field out of its range is not set and matches
+        // any value.
+        let field = |index: usize, min: u8, max: u8| {
+            data.get(index)
+                .copied()
+                .and_then(bcd)
+                .filter(|v| (min..=max).contains(v))
+        };
+        let two = |value: Option<u8>| value.map_or("*".to_string(), |v| format!("{v:02}"));
+
+        let month = match field(0, 1, 12) {
+            Some(m) => MONTHS[m as usize - 1].to_string(),
+            None => "*".to_string(),
+        };
+        let next_power_on = format!(
+            "{month} {}, {}:{}:{}",
+            field(1, 1, 31).map_or("*".to_string(), |d| d.to_string()),
+            two(field(2, 0, 23)),
+            two(field(3, 0, 59)),
+            two(field(4, 0, 59)),
+        );
+        Self { next_power_on }
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn bcd(value: u8) -> Option<u8> {
+    let (high, low) = (value >> 4, value & 0x0F);
+    (high <= 9 && low <= 9).then_some(high * 10 + low)
+}
+
+serialize_display!(BootStatus);
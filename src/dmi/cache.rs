@@ -1,8 +1,13 @@
 // SMBIOS Type 7 (Cache Information). Spec reference: DSP0134 §7.8.
 
-#[derive(Debug)]
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use crate::dmi::serialize_display;
+
+#[derive(Debug, Serialize)]
 pub struct Cache {
     pub handle: u16,
+    #[serde(flatten)]
     installed_size: CacheSize,
     cache_type: CacheType,
 }
@@ -90,6 +95,25 @@ impl std::fmt::Display for CacheSize {
     }
 }
 
+// Modelled as the display size plus its value in KB (0 when not installed,
+// null when unknown).
+impl Serialize for CacheSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let kilobytes = match self {
+            CacheSize::NotInstalled => Some(0),
+            CacheSize::Unknown => None,
+            CacheSize::Kilobytes(kb) => Some(*kb),
+        };
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("installed_size", &self.to_string())?;
+        map.serialize_entry("installed_size_kb", &kilobytes)?;
+        map.end()
+    }
+}
+
+serialize_display!(CacheType);
+
 #[derive(Debug, strum::Display)]
 enum CacheType {
     #[strum(to_string = "Other")]
@@ -6,8 +6,11 @@ use ratatui::{
     style::Stylize,
     widgets::{Block, Cell, Padding, Row, Table},
 };
+use serde::Serialize;
 
-#[derive(Debug)]
+use crate::dmi::{mask, serialize_display};
+
+#[derive(Debug, Serialize)]
 pub struct Chassis {
     manufacturer: String,
     chassis_type: ChassisType,
@@ -178,8 +181,8 @@ impl From<u8> for State {
     }
 }
 
-#[derive(Debug, strum::Display)]
-enum SecurityStatus {
+#[derive(Debug, Clone, Copy, strum::Display)]
+pub enum SecurityStatus {
     #[strum(to_string = "Other")]
     Other,
     #[strum(to_string = "Unknown")]
@@ -205,6 +208,15 @@ impl From<u8> for SecurityStatus {
     }
 }
 
+serialize_display!(ChassisType, State, SecurityStatus);
+
+impl SecurityStatus {
+    // Nothing guards the external interfaces, such as a keyboard or a port.
+    pub fn is_insecure(&self) -> bool {
+        matches!(self, Self::None | Self::ExternalInterfaceEnabled)
+    }
+}
+
 impl From<(Vec<u8>, Vec<String>)> for Chassis {
     fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
         Self {
@@ -224,14 +236,25 @@ impl From<(Vec<u8>, Vec<String>)> for Chassis {
             contained_element_count: data[15],
             contained_element_record_length: data[16],
             contained_elements: Vec::new(),
-            sku_number: text[data[17 + (data[15] * data[16]) as usize].saturating_sub(1) as usize]
-                .clone(),
+            // SMBIOS 2.7 and later, after the contained elements.
+            sku_number: data.get(17 + data[15] as usize * data[16] as usize).map_or(
+                String::new(),
+                |&i| {
+                    text.get(i.saturating_sub(1) as usize)
+                        .cloned()
+                        .unwrap_or_default()
+                },
+            ),
         }
     }
 }
 
 impl Chassis {
-    pub fn render(&self, frame: &mut Frame, block: Rect) {
+    pub fn security_status(&self) -> SecurityStatus {
+        self.security_status
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
         let rows = vec![
             Row::new(vec![
                 Cell::from("Manufacturer").bold(),
@@ -255,11 +278,11 @@ impl Chassis {
             ]),
             Row::new(vec![
                 Cell::from("Serial number").bold(),
-                Cell::from(self.serial_number.clone()),
+                Cell::from(mask(&self.serial_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Asset Tag Number").bold(),
-                Cell::from(self.asset_tag_number.clone()),
+                Cell::from(mask(&self.asset_tag_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Bootup State").bold(),
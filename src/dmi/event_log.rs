@@ -0,0 +1,495 @@
+// SMBIOS Type 15 (System Event Log). Spec reference: DSP0134 §7.16.
+//
+// The structure only describes where the firmware keeps its event log. When
+// the log area is memory-mapped it can be read from /dev/mem, or from a memory
+// image for tables read from a file.
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Cell, Row, Table, TableState},
+};
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+pub const DEV_MEM: &str = "/dev/mem";
+
+const ACCESS_MEMORY_MAPPED: u8 = 0x03;
+
+#[derive(Debug)]
+pub struct EventLog {
+    area_length: u16,
+    header_start: u16,
+    data_start: u16,
+    access_method: u8,
+    // Raw access method address: a physical address, two I/O ports or a
+    // GPNV handle, depending on the access method.
+    access_address: u32,
+    valid: bool,
+    full: bool,
+    change_token: u32,
+    header_format: u8,
+    // Supported event types with the format of their variable data.
+    descriptors: Vec<(u8, u8)>,
+    // What each handle in the table points at, for events that refer to one.
+    names: HashMap<u16, String>,
+    events: Result<Vec<Event>, String>,
+    selected: usize,
+}
+
+impl From<(Vec<u8>, Vec<String>)> for EventLog {
+    fn from((data, _text): (Vec<u8>, Vec<String>)) -> Self {
+        let u16_at = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+
+        // SMBIOS 2.1 and later: the list of supported event type descriptors.
+        let descriptors = match (data.get(0x11), data.get(0x12)) {
+            (Some(&count), Some(&length)) if length >= 2 => data[0x13..]
+                .chunks_exact(length as usize)
+                .take(count as usize)
+                .map(|d| (d[0], d[1]))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Self {
+            area_length: u16_at(0x00),
+            header_start: u16_at(0x02),
+            data_start: u16_at(0x04),
+            access_method: data[0x06],
+            access_address: u32_at(0x0C),
+            valid: data[0x07] & 0x01 != 0,
+            full: data[0x07] & 0x02 != 0,
+            change_token: u32_at(0x08),
+            header_format: data.get(0x10).copied().unwrap_or(0),
+            descriptors,
+            names: HashMap::new(),
+            events: Err("Log area not read, see --memory".to_string()),
+            selected: 0,
+        }
+    }
+}
+
+impl EventLog {
+    pub fn set_names(&mut self, names: HashMap<u16, String>) {
+        self.names = names;
+    }
+
+    /// Reads and decodes the log area from `memory`, a view of physical
+    /// memory such as /dev/mem. A file holding only the log area, as long as
+    /// the area itself, is read from its start instead.
+    pub fn read(&mut self, memory: &Path) {
+        if self.access_method != ACCESS_MEMORY_MAPPED {
+            self.events = Err(format!(
+                "The log is accessed through {}, which dmitui can't read",
+                access_method_name(self.access_method)
+            ));
+            return;
+        }
+
+        let read_area = || -> std::io::Result<Vec<u8>> {
+            let mut file = File::open(memory)?;
+            let whole_area = file
+                .metadata()
+                .is_ok_and(|m| m.is_file() && m.len() == self.area_length as u64);
+            if !whole_area {
+                file.seek(SeekFrom::Start(self.access_address as u64))?;
+            }
+            let mut area = vec![0; self.area_length as usize];
+            file.read_exact(&mut area)?;
+            Ok(area)
+        };
+
+        self.events = read_area()
+            .map(|area| parse_events(area.get(self.data_start as usize..).unwrap_or_default()))
+            .map_err(|e| format!("Could not read {}: {e}", memory.display()));
+        self.selected = 0;
+    }
+
+    fn event_count(&self) -> usize {
+        self.events.as_ref().map_or(0, Vec::len)
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.event_count() >= 2
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        let count = self.event_count();
+        if count == 0 {
+            return;
+        }
+        let last = count - 1;
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % count;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + last) % count;
+            }
+            KeyCode::PageDown => self.selected = (self.selected + 10).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(10),
+            KeyCode::Home | KeyCode::Char('g') => self.selected = 0,
+            KeyCode::End | KeyCode::Char('G') => self.selected = last,
+            _ => {}
+        }
+    }
+
+    // Variable data of an event, decoded according to the format declared
+    // for its type.
+    fn details(&self, event: &Event) -> String {
+        let format = self
+            .descriptors
+            .iter()
+            .find(|(event_type, _)| *event_type == event.event_type)
+            .map(|(_, format)| *format);
+        let data = &event.data;
+        let u16_at = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        };
+        let u32_at = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        };
+        let handle = |handle: u16| match self.names.get(&handle) {
+            Some(name) => format!("{name} ({handle:#06x})"),
+            None => format!("Handle {handle:#06x}"),
+        };
+
+        let decoded = match format {
+            Some(0x01) => u16_at(0).map(handle),
+            Some(0x02) => u32_at(0).map(|count| format!("{count} events")),
+            Some(0x03) => u16_at(0)
+                .zip(u32_at(2))
+                .map(|(h, count)| format!("{}, {count} events", handle(h))),
+            Some(0x04) => u32_at(0)
+                .zip(u32_at(4))
+                .map(|(first, second)| format!("POST results {first:#010x} {second:#010x}")),
+            Some(0x05) => u32_at(0).map(|t| format!("System management type {t:#010x}")),
+            Some(0x06) => u32_at(0)
+                .zip(u32_at(4))
+                .map(|(t, count)| format!("System management type {t:#010x}, {count} events")),
+            _ => None,
+        };
+        decoded.unwrap_or_else(|| {
+            data.iter()
+                .map(|b| format!("{b:02X}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let status = match (self.valid, self.full) {
+            (false, _) => "Invalid",
+            (true, true) => "Valid, full",
+            (true, false) => "Valid",
+        };
+        let summary = vec![
+            Row::new(vec![
+                Cell::from("Access Method").bold(),
+                Cell::from(access_method_name(self.access_method)),
+            ]),
+            Row::new(vec![
+                Cell::from("Access Address").bold(),
+                Cell::from(self.access_address_text()),
+            ]),
+            Row::new(vec![
+                Cell::from("Area").bold(),
+                Cell::from(format!(
+                    "{} bytes, header at {:#06x}, data at {:#06x}, {}",
+                    self.area_length,
+                    self.header_start,
+                    self.data_start,
+                    header_format_name(self.header_format)
+                )),
+            ]),
+            Row::new(vec![
+                Cell::from("Status").bold(),
+                Cell::from(format!(
+                    "{status}, change token {:#010x}",
+                    self.change_token
+                )),
+            ]),
+        ];
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
+        frame.render_widget(Table::new(summary, widths), chunks[0]);
+
+        let events = match &self.events {
+            Ok(events) if !events.is_empty() => events,
+            Ok(_) => {
+                frame.render_widget(Line::from("The log is empty").dim(), chunks[1]);
+                return;
+            }
+            Err(reason) => {
+                self.render_supported_types(frame, chunks[1], reason);
+                return;
+            }
+        };
+
+        let rows: Vec<Row<'_>> = events
+            .iter()
+            .map(|e| {
+                let row = Row::new(vec![
+                    e.time.clone().unwrap_or_else(|| "Invalid date".to_string()),
+                    event_type_name(e.event_type),
+                    self.details(e),
+                ]);
+                if e.read { row.dim() } else { row }
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(19),
+            Constraint::Length(36),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec![
+                    "Time".to_string(),
+                    format!("Event ({} of {})", self.selected + 1, events.len()),
+                    "Details".to_string(),
+                ])
+                .bold()
+                .bottom_margin(1),
+            )
+            .column_spacing(2)
+            .row_highlight_style(Style::new().bold().reversed());
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, chunks[1], &mut state);
+    }
+
+    // Without the events, what the firmware says it can log.
+    fn render_supported_types(&self, frame: &mut Frame, block: Rect, reason: &str) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Fill(1)])
+            .split(block);
+        frame.render_widget(Line::from(reason.to_string()).dim(), chunks[0]);
+
+        let rows: Vec<Row<'_>> = self
+            .descriptors
+            .iter()
+            .map(|&(event_type, format)| {
+                Row::new(vec![event_type_name(event_type), data_format_name(format)])
+            })
+            .collect();
+        let widths = [Constraint::Length(36), Constraint::Fill(1)];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Supported Event", "Data Format"])
+                    .bold()
+                    .bottom_margin(1),
+            )
+            .column_spacing(2);
+        frame.render_widget(table, chunks[1]);
+    }
+
+    fn access_address_text(&self) -> String {
+        let [a, b, c, d] = self.access_address.to_le_bytes();
+        match self.access_method {
+            0x00..=0x02 => format!(
+                "Index port {:#06x}, data port {:#06x}",
+                u16::from_le_bytes([a, b]),
+                u16::from_le_bytes([c, d])
+            ),
+            ACCESS_MEMORY_MAPPED => format!("{:#010x}", self.access_address),
+            0x04 => format!("GPNV handle {:#06x}", u16::from_le_bytes([a, b])),
+            _ => format!("{:#010x}", self.access_address),
+        }
+    }
+}
+
+impl Serialize for EventLog {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Supported {
+            event_type: String,
+            data_format: String,
+        }
+        #[derive(Serialize)]
+        struct Entry<'a> {
+            time: &'a Option<String>,
+            event_type: String,
+            read: bool,
+            details: String,
+        }
+
+        let supported: Vec<Supported> = self
+            .descriptors
+            .iter()
+            .map(|&(event_type, format)| Supported {
+                event_type: event_type_name(event_type),
+                data_format: data_format_name(format),
+            })
+            .collect();
+        // Null when the log area couldn't be read.
+        let events: Option<Vec<Entry<'_>>> = self.events.as_ref().ok().map(|events| {
+            events
+                .iter()
+                .map(|e| Entry {
+                    time: &e.time,
+                    event_type: event_type_name(e.event_type),
+                    read: e.read,
+                    details: self.details(e),
+                })
+                .collect()
+        });
+
+        let mut state = serializer.serialize_struct("EventLog", 10)?;
+        state.serialize_field("access_method", &access_method_name(self.access_method))?;
+        state.serialize_field("access_address", &self.access_address_text())?;
+        state.serialize_field("area_length", &self.area_length)?;
+        state.serialize_field("header_start", &self.header_start)?;
+        state.serialize_field("data_start", &self.data_start)?;
+        state.serialize_field("header_format", &header_format_name(self.header_format))?;
+        state.serialize_field("valid", &self.valid)?;
+        state.serialize_field("full", &self.full)?;
+        state.serialize_field("supported_events", &supported)?;
+        state.serialize_field("events", &events)?;
+        state.end()
+    }
+}
+
+#[derive(Debug)]
+struct Event {
+    event_type: u8,
+    // Set once software has processed the record.
+    read: bool,
+    time: Option<String>,
+    data: Vec<u8>,
+}
+
+// Records follow each other up to the end of the area, or to a record of
+// type 0xFF that marks the start of unused space.
+fn parse_events(mut area: &[u8]) -> Vec<Event> {
+    let mut events = Vec::new();
+    while let [event_type, length, ..] = *area {
+        let length = (length & 0x7F) as usize;
+        if event_type == 0xFF || length < 8 || length > area.len() {
+            break;
+        }
+        events.push(Event {
+            event_type,
+            // The top bit of the length is cleared once the record is read.
+            read: area[1] & 0x80 == 0,
+            time: timestamp(&area[2..8]),
+            data: area[8..length].to_vec(),
+        });
+        area = &area[length..];
+    }
+    events
+}
+
+// Year, month, day, hour, minute and second, each in BCD. Years 80 to 99
+// are in the 1900s.
+fn timestamp(bcd: &[u8]) -> Option<String> {
+    let values: Vec<u8> = bcd
+        .iter()
+        .map(|&b| (b >> 4 < 10 && b & 0x0F < 10).then_some((b >> 4) * 10 + (b & 0x0F)))
+        .collect::<Option<_>>()?;
+    let year = if values[0] >= 80 {
+        1900 + values[0] as u16
+    } else {
+        2000 + values[0] as u16
+    };
+    Some(format!(
+        "{year}-{:02}-{:02} {:02}:{:02}:{:02}",
+        values[1], values[2], values[3], values[4], values[5]
+    ))
+}
+
+// Spec reference: SMBIOS DSP0134 §7.16.3.
+fn access_method_name(code: u8) -> String {
+    match code {
+        0x00 => "Indexed I/O, one 8-bit index port".to_string(),
+        0x01 => "Indexed I/O, two 8-bit index ports".to_string(),
+        0x02 => "Indexed I/O, one 16-bit index port".to_string(),
+        0x03 => "Memory-mapped physical 32-bit address".to_string(),
+        0x04 => "General-purpose non-volatile data functions".to_string(),
+        0x80..=0xFF => format!("OEM access method {code:#x}"),
+        _ => format!("Access method {code:#x}"),
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.16.5.
+fn header_format_name(code: u8) -> String {
+    match code {
+        0x00 => "no header".to_string(),
+        0x01 => "type 1 header".to_string(),
+        0x80..=0xFF => format!("OEM header {code:#x}"),
+        _ => format!("header format {code:#x}"),
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.16.6.1.
+const EVENT_TYPE: &[&str] = &[
+    "Reserved",                            // 0x00
+    "Single-bit ECC memory error",         // 0x01
+    "Multi-bit ECC memory error",          // 0x02
+    "Parity memory error",                 // 0x03
+    "Bus time-out",                        // 0x04
+    "I/O channel check",                   // 0x05
+    "Software NMI",                        // 0x06
+    "POST memory resize",                  // 0x07
+    "POST error",                          // 0x08
+    "PCI parity error",                    // 0x09
+    "PCI system error",                    // 0x0A
+    "CPU failure",                         // 0x0B
+    "EISA FailSafe timer time-out",        // 0x0C
+    "Correctable memory log disabled",     // 0x0D
+    "Logging disabled for an event type",  // 0x0E
+    "Reserved",                            // 0x0F
+    "System limit exceeded",               // 0x10
+    "Asynchronous hardware timer expired", // 0x11
+    "System configuration information",    // 0x12
+    "Hard disk information",               // 0x13
+    "System reconfigured",                 // 0x14
+    "Uncorrectable CPU-complex error",     // 0x15
+    "Log area reset/cleared",              // 0x16
+    "System boot",                         // 0x17
+];
+
+fn event_type_name(code: u8) -> String {
+    match code {
+        0x00..=0x17 => EVENT_TYPE[code as usize].to_string(),
+        0x80..=0xFE => format!("OEM event {code:#x}"),
+        0xFF => "End of log".to_string(),
+        _ => format!("Event type {code:#x}"),
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.16.6.2.
+const DATA_FORMAT: &[&str] = &[
+    "None",                                  // 0x00
+    "Handle",                                // 0x01
+    "Multiple-event",                        // 0x02
+    "Multiple-event handle",                 // 0x03
+    "POST results bitmap",                   // 0x04
+    "System management type",                // 0x05
+    "Multiple-event system management type", // 0x06
+];
+
+fn data_format_name(code: u8) -> String {
+    match code {
+        0x00..=0x06 => DATA_FORMAT[code as usize].to_string(),
+        0x80..=0xFF => format!("OEM format {code:#x}"),
+        _ => format!("Data format {code:#x}"),
+    }
+}
@@ -6,6 +6,9 @@ use ratatui::{
     style::{Style, Stylize},
     widgets::{Block, Cell, Padding, Row, Table},
 };
+use serde::Serialize;
+
+use crate::dmi::serialize_display;
 
 #[derive(Debug)]
 pub struct Release {
@@ -25,7 +28,9 @@ impl Display for Release {
     }
 }
 
-#[derive(Debug)]
+serialize_display!(Release);
+
+#[derive(Debug, Serialize)]
 pub struct LanguageInfos {
     installed_languages: u8,
     abbreviated_format_is_used: bool,
@@ -42,7 +47,7 @@ impl From<(Vec<u8>, Vec<String>)> for LanguageInfos {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Firmware {
     pub vendor: String,
     pub firmware_version: String,
@@ -50,6 +55,7 @@ pub struct Firmware {
     pub firmware_release_date: String,
     pub firmware_rom_size: String,
     pub firmware_characteristics: FirmwareCharacteristics,
+    #[serde(rename = "firmware_characteristics_extensions")]
     pub firmware_characteristics_exentions: FirmwareCharacteristicsExtension,
     pub platform_firmware_release: Release,
     pub embedded_controller_firmware_release: Release,
@@ -62,7 +68,8 @@ impl From<(Vec<u8>, Vec<String>, u8)> for Firmware {
         let rom_size = {
             let firmware_rom_size: u16 = (data[5] as u16 + 1) * 64;
 
-            if firmware_rom_size < (16 * 1024) {
+            // The extended size is only there from SMBIOS 3.1.
+            if firmware_rom_size < (16 * 1024) || data.len() < 22 {
                 format!("{firmware_rom_size}K")
             } else {
                 let unit = (data[20] & 0b11000000) >> 6;
@@ -516,7 +523,7 @@ impl Firmware {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct FirmwareCharacteristics {
     pub supported: bool,
     pub isa: bool,
@@ -587,7 +594,7 @@ impl From<&[u8]> for FirmwareCharacteristics {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct FirmwareCharacteristicsExtension {
     pub acpi: bool,
     pub usb_legacy: bool,
@@ -595,6 +602,7 @@ pub struct FirmwareCharacteristicsExtension {
     pub i2o_boot: bool,
     pub ls_120_superdisk_boot: bool,
     pub atapi_zip_drive_boot: bool,
+    #[serde(rename = "ieee_1394_boot")]
     pub _1394_boot: bool,
     pub smart_battery: bool,
     pub bios_boot_spec: bool,
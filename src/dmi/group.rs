@@ -0,0 +1,325 @@
+// SMBIOS Type 14 (Group Associations). Spec reference: DSP0134 §7.15.
+//
+// A group names a set of structures by handle. Handles are resolved once the
+// whole table has been read, since members may come after the group.
+use std::{collections::HashMap, fmt};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    widgets::{
+        Block, BorderType, Borders, List, ListItem, ListState, Padding, Row, Table, TableState,
+    },
+};
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+fn string_ref(idx: u8, text: &[String]) -> String {
+    if idx == 0 {
+        return "Not Specified".to_string();
+    }
+    text.get((idx - 1) as usize)
+        .cloned()
+        .unwrap_or_else(|| "Not Specified".to_string())
+}
+
+// What a handle points at, for display in a group: the structure type and,
+// for types that have one, the designation it is known by.
+#[derive(Debug, Clone)]
+pub struct Target {
+    structure_type: u8,
+    designation: Option<String>,
+}
+
+impl Target {
+    pub fn new(structure_type: u8, data: &[u8], text: &[String]) -> Self {
+        let string_at = |offset: usize| data.get(offset).map(|&idx| string_ref(idx, text));
+        let designation = match structure_type {
+            1 | 2 => string_at(1),          // product name
+            4 | 7 | 9 | 41 => string_at(0), // socket, slot or device designation
+            // external, else internal designator
+            8 => match string_at(2) {
+                Some(external) if external != "Not Specified" => Some(external),
+                _ => string_at(0),
+            },
+            17 => string_at(12),                    // device locator
+            22 => string_at(4),                     // device name
+            26 | 28 | 29 | 34 | 35 => string_at(0), // description
+            27 => string_at(10),                    // description
+            39 => string_at(1),                     // location
+            _ => None,
+        };
+        Self {
+            structure_type,
+            designation,
+        }
+    }
+}
+
+// "Memory Device DIMM_A1"
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", structure_type_name(self.structure_type))?;
+        if let Some(designation) = &self.designation {
+            write!(f, " {designation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Groups {
+    list: Vec<Group>,
+    // Index into the members of all groups, in order.
+    selected: usize,
+}
+
+impl Groups {
+    pub fn new(mut list: Vec<Group>, targets: &HashMap<u16, Target>) -> Option<Self> {
+        if list.is_empty() {
+            return None;
+        }
+        for member in list.iter_mut().flat_map(|g| &mut g.members) {
+            member.target = targets.get(&member.handle).cloned();
+        }
+        Some(Self { list, selected: 0 })
+    }
+
+    fn member_count(&self) -> usize {
+        self.list.iter().map(|g| g.members.len()).sum()
+    }
+
+    // Group and member index of the selected member.
+    fn position(&self) -> Option<(usize, usize)> {
+        let mut rest = self.selected;
+        for (i, group) in self.list.iter().enumerate() {
+            if rest < group.members.len() {
+                return Some((i, rest));
+            }
+            rest -= group.members.len();
+        }
+        None
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.member_count() >= 2
+    }
+
+    /// Moves through the members of every group. Enter returns the structure
+    /// type and handle of the selected member, so the caller can open its
+    /// section on it.
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) -> Option<(u8, u16)> {
+        let count = self.member_count();
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') if count > 0 => {
+                self.selected = (self.selected + 1) % count;
+            }
+            KeyCode::Up | KeyCode::Char('k') if count > 0 => {
+                self.selected = (self.selected + count - 1) % count;
+            }
+            KeyCode::Enter => {
+                let (group, member) = self.position()?;
+                let member = &self.list[group].members[member];
+                return Some((member.target.as_ref()?.structure_type, member.handle));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect) {
+        let position = self.position();
+
+        let max_label = self
+            .list
+            .iter()
+            .map(|g| g.name.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        // 4 = 2 borders + 2 horizontal padding
+        let list_width = max_label.saturating_add(4).max(14);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let items: Vec<ListItem<'_>> = self
+            .list
+            .iter()
+            .map(|g| ListItem::new(g.name.clone()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 1, 0)),
+            )
+            .highlight_style(Style::new().bold().reversed())
+            .highlight_symbol("");
+
+        let mut state = ListState::default();
+        state.select(Some(position.map_or(0, |(group, _)| group)));
+        frame.render_stateful_widget(list, body[0], &mut state);
+
+        let group = &self.list[position.map_or(0, |(group, _)| group)];
+        let rows: Vec<Row<'_>> = group
+            .members
+            .iter()
+            .map(|m| {
+                let (kind, designation) = match &m.target {
+                    Some(target) => (
+                        structure_type_name(target.structure_type),
+                        target.designation.clone().unwrap_or_default(),
+                    ),
+                    None => (
+                        structure_type_name(m.structure_type),
+                        "Handle not found".to_string(),
+                    ),
+                };
+                let row = Row::new(vec![kind, format!("{:#06x}", m.handle), designation]);
+                if m.target.is_some() { row } else { row.dim() }
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(32),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Type", "Handle", "Designation"])
+                    .bold()
+                    .bottom_margin(1),
+            )
+            .column_spacing(2)
+            .row_highlight_style(Style::new().bold().reversed())
+            .block(Block::new().padding(Padding::uniform(2)));
+        let mut state = TableState::default().with_selected(position.map(|(_, member)| member));
+        frame.render_stateful_widget(table, body[1].inner(Margin::new(2, 0)), &mut state);
+    }
+}
+
+impl Serialize for Groups {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Group {
+    name: String,
+    members: Vec<Member>,
+}
+
+impl From<(Vec<u8>, Vec<String>)> for Group {
+    // The group name, then one 3 byte entry per member: type and handle.
+    fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
+        let members = data
+            .get(1..)
+            .unwrap_or_default()
+            .chunks_exact(3)
+            .map(|entry| Member {
+                structure_type: entry[0],
+                handle: u16::from_le_bytes([entry[1], entry[2]]),
+                target: None,
+            })
+            .collect();
+
+        Self {
+            name: string_ref(data[0], &text),
+            members,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Member {
+    structure_type: u8,
+    handle: u16,
+    // Filled in by `Groups::new`; None when no structure has this handle.
+    target: Option<Target>,
+}
+
+impl Serialize for Member {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let structure_type = self
+            .target
+            .as_ref()
+            .map_or(self.structure_type, |t| t.structure_type);
+        let mut state = serializer.serialize_struct("Member", 4)?;
+        state.serialize_field("type", &structure_type_name(structure_type))?;
+        state.serialize_field("handle", &format!("{:#06x}", self.handle))?;
+        state.serialize_field("found", &self.target.is_some())?;
+        state.serialize_field(
+            "designation",
+            &self.target.as_ref().and_then(|t| t.designation.as_ref()),
+        )?;
+        state.end()
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.
+const STRUCTURE_TYPE: &[&str] = &[
+    "Firmware",                             // 0
+    "System",                               // 1
+    "Baseboard",                            // 2
+    "Chassis",                              // 3
+    "Processor",                            // 4
+    "Memory Controller",                    // 5
+    "Memory Module",                        // 6
+    "Cache",                                // 7
+    "Port Connector",                       // 8
+    "System Slot",                          // 9
+    "On Board Devices",                     // 10
+    "OEM Strings",                          // 11
+    "System Configuration Options",         // 12
+    "Firmware Language",                    // 13
+    "Group Associations",                   // 14
+    "System Event Log",                     // 15
+    "Physical Memory Array",                // 16
+    "Memory Device",                        // 17
+    "32-Bit Memory Error",                  // 18
+    "Memory Array Mapped Address",          // 19
+    "Memory Device Mapped Address",         // 20
+    "Built-in Pointing Device",             // 21
+    "Portable Battery",                     // 22
+    "System Reset",                         // 23
+    "Hardware Security",                    // 24
+    "System Power Controls",                // 25
+    "Voltage Probe",                        // 26
+    "Cooling Device",                       // 27
+    "Temperature Probe",                    // 28
+    "Electrical Current Probe",             // 29
+    "Out-of-Band Remote Access",            // 30
+    "Boot Integrity Services",              // 31
+    "System Boot",                          // 32
+    "64-Bit Memory Error",                  // 33
+    "Management Device",                    // 34
+    "Management Device Component",          // 35
+    "Management Device Threshold Data",     // 36
+    "Memory Channel",                       // 37
+    "IPMI Device",                          // 38
+    "System Power Supply",                  // 39
+    "Additional Information",               // 40
+    "Onboard Devices Extended Information", // 41
+    "Management Controller Host Interface", // 42
+    "TPM Device",                           // 43
+    "Processor Additional Information",     // 44
+    "Firmware Inventory",                   // 45
+    "String Property",                      // 46
+];
+
+fn structure_type_name(code: u8) -> String {
+    match code {
+        0..=46 => STRUCTURE_TYPE[code as usize].to_string(),
+        126 => "Inactive".to_string(),
+        127 => "End of Table".to_string(),
+        128..=255 => format!("OEM type {code}"),
+        _ => format!("Type {code}"),
+    }
+}
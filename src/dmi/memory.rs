@@ -6,26 +6,114 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
 };
+use serde::{Serialize, Serializer, ser::SerializeMap};
 
-#[derive(Debug)]
+use crate::dmi::address_map::AddressMap;
+use crate::dmi::memory_error::MemoryError;
+use crate::dmi::{mask, mask_suffix, serialize_display};
+
+#[derive(Debug, Serialize)]
 pub struct Memory {
+    #[serde(rename = "array")]
     pub physical_memory_array: PhysicalMemoryArray,
+    #[serde(rename = "devices")]
     pub memory_devices: Vec<MemoryDevice>,
+    address_map: AddressMap,
+    #[serde(skip)]
     selected_device: usize,
+    #[serde(skip)]
+    view: View,
+    // Address being typed at the lookup prompt.
+    #[serde(skip)]
+    prompt: Option<String>,
+    // Last looked up address and what it maps to.
+    #[serde(skip)]
+    lookup: Option<(Option<u64>, String)>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum View {
+    #[default]
+    Devices,
+    AddressMap,
 }
 
 impl Memory {
     pub fn new(
-        physical_memory_array: PhysicalMemoryArray,
-        memory_devices: Vec<MemoryDevice>,
+        mut physical_memory_array: PhysicalMemoryArray,
+        mut memory_devices: Vec<MemoryDevice>,
+        errors: &[MemoryError],
+        mut address_map: AddressMap,
     ) -> Self {
+        let find_error = |handle: Option<u16>| {
+            handle.and_then(|h| errors.iter().find(|e| e.handle == h).cloned())
+        };
+        physical_memory_array.error = find_error(physical_memory_array.error_information_handle);
+        for device in &mut memory_devices {
+            device.error = find_error(device.error_information_handle);
+        }
+
+        let locators: Vec<(u16, String)> = memory_devices
+            .iter()
+            .map(|d| (d.handle, d.device_locator.clone()))
+            .collect();
+        address_map.resolve(&locators);
+
         Self {
             physical_memory_array,
             memory_devices,
+            address_map,
             selected_device: 0,
+            view: View::Devices,
+            prompt: None,
+            lookup: None,
+        }
+    }
+
+    pub fn has_address_map(&self) -> bool {
+        !self.address_map.is_empty()
+    }
+
+    /// Whether an address is being typed at the lookup prompt.
+    pub fn is_editing(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    /// Selects the device at `index`, in table order, in the device list.
+    pub fn select_device(&mut self, index: usize) {
+        if index < self.memory_devices.len() {
+            self.selected_device = index;
+            self.view = View::Devices;
         }
     }
 
+    // Looks up a hexadecimal address, with or without 0x, and selects the
+    // device that holds it.
+    fn look_up(&mut self, input: &str) {
+        let digits = input.trim().trim_start_matches("0x");
+        let Ok(address) = u64::from_str_radix(digits, 16) else {
+            self.lookup = Some((None, format!("Not a hexadecimal address: {input}")));
+            return;
+        };
+
+        // By handle, as several devices may share a locator.
+        let (handles, message) = self.address_map.lookup(address);
+        if let Some(index) = handles
+            .first()
+            .and_then(|handle| self.memory_devices.iter().position(|d| d.handle == *handle))
+        {
+            self.selected_device = index;
+        }
+        self.lookup = Some((Some(address), message));
+    }
+
+    fn devices_with_errors(&self) -> usize {
+        self.memory_devices
+            .iter()
+            .filter(|d| d.error.as_ref().is_some_and(MemoryError::is_error))
+            .count()
+    }
+
     fn device_layout(&self) -> DeviceLayout {
         let mut has_soldered = false;
         let mut has_socketed = false;
@@ -44,6 +132,38 @@ impl Memory {
     }
 
     pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        if let Some(prompt) = &mut self.prompt {
+            match key_event.code {
+                KeyCode::Char(c) if c.is_ascii_hexdigit() || c == 'x' => prompt.push(c),
+                KeyCode::Backspace => {
+                    prompt.pop();
+                }
+                KeyCode::Enter => {
+                    let input = std::mem::take(prompt);
+                    self.prompt = None;
+                    self.look_up(&input);
+                }
+                KeyCode::Esc => self.prompt = None,
+                _ => {}
+            }
+            return;
+        }
+
+        match key_event.code {
+            KeyCode::Char('m') if self.has_address_map() => {
+                self.view = match self.view {
+                    View::Devices => View::AddressMap,
+                    View::AddressMap => View::Devices,
+                };
+                return;
+            }
+            KeyCode::Char('a') if self.has_address_map() => {
+                self.prompt = Some(String::new());
+                return;
+            }
+            _ => {}
+        }
+
         if self.memory_devices.is_empty() {
             return;
         }
@@ -59,15 +179,25 @@ impl Memory {
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, block: Rect) {
+    pub fn render(&mut self, frame: &mut Frame, block: Rect, redact: bool) {
         if self.memory_devices.is_empty() {
             self.physical_memory_array.render(frame, block);
             return;
         }
 
+        // The lookup line only takes room when there is something to show.
+        let lookup_height = if self.prompt.is_some() || self.lookup.is_some() {
+            2
+        } else {
+            0
+        };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Fill(1)])
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(lookup_height),
+                Constraint::Fill(1),
+            ])
             .split(block.inner(Margin::new(4, 2)));
 
         let count_label = match self.device_layout() {
@@ -76,7 +206,7 @@ impl Memory {
             DeviceLayout::Mixed => "Devices: ",
         };
 
-        let summary = Line::from(vec![
+        let mut summary = vec![
             Span::from("Total Capacity: ").bold(),
             Span::from(self.physical_memory_array.max_capacity.clone()),
             Span::from("    "),
@@ -85,27 +215,76 @@ impl Memory {
             Span::from("    "),
             Span::from("ECC: ").bold(),
             Span::from(self.physical_memory_array.error_correction.to_string()),
-        ]);
+        ];
+        match self.devices_with_errors() {
+            0 => {}
+            1 => summary.push(Span::from("    1 device with errors").red().bold()),
+            n => summary.push(
+                Span::from(format!("    {n} devices with errors"))
+                    .red()
+                    .bold(),
+            ),
+        }
+        if let Some(error) = &self.physical_memory_array.error
+            && error.is_error()
+        {
+            summary.push(
+                Span::from(format!("    Array: {}", error.summary()))
+                    .red()
+                    .bold(),
+            );
+        }
+        let summary = Line::from(summary);
         frame.render_widget(summary, chunks[0]);
 
+        if let Some(prompt) = &self.prompt {
+            let line = Line::from(vec![
+                Span::from("Address: ").bold(),
+                Span::from(prompt.clone()),
+                Span::from("▏"),
+            ]);
+            frame.render_widget(line, chunks[1]);
+        } else if let Some((_, message)) = &self.lookup {
+            let line = Line::from(vec![
+                Span::from("Lookup: ").bold(),
+                Span::from(message.clone()),
+            ]);
+            frame.render_widget(line, chunks[1]);
+        }
+
+        if self.view == View::AddressMap {
+            let highlight = self.lookup.as_ref().and_then(|(address, _)| *address);
+            self.address_map
+                .render(frame, chunks[2].inner(Margin::new(0, 1)), highlight);
+            return;
+        }
+
         let max_label = self
             .memory_devices
             .iter()
             .map(|d| d.device_locator.chars().count())
             .max()
-            .unwrap_or(0) as u16;
+            .unwrap_or(0) as u16
+            // Room for the error marker
+            + 2;
         // 4 = 2 borders + 2 horizontal padding
         let list_width = max_label.saturating_add(4).max(14);
 
         let body = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
-            .split(chunks[1]);
+            .split(chunks[2]);
 
         let items: Vec<ListItem<'_>> = self
             .memory_devices
             .iter()
-            .map(|d| ListItem::new(d.device_locator.clone()))
+            .map(|d| {
+                if d.error.as_ref().is_some_and(MemoryError::is_error) {
+                    ListItem::new(format!("{} !", d.device_locator)).red()
+                } else {
+                    ListItem::new(d.device_locator.clone())
+                }
+            })
             .collect();
 
         let list = List::new(items)
@@ -123,7 +302,7 @@ impl Memory {
         frame.render_stateful_widget(list, body[0], &mut state);
 
         if let Some(device) = self.memory_devices.get(self.selected_device) {
-            device.render(frame, body[1]);
+            device.render(frame, body[1], redact);
         }
     }
 }
@@ -142,7 +321,7 @@ enum FormFactorKind {
     Unknown,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct PhysicalMemoryArray {
     location: Location,
     function: Function,
@@ -150,6 +329,7 @@ pub struct PhysicalMemoryArray {
     max_capacity: String,
     error_information_handle: Option<u16>,
     number_memory_devices: u16,
+    error: Option<MemoryError>,
 }
 
 impl From<&[u8]> for PhysicalMemoryArray {
@@ -189,13 +369,14 @@ impl From<&[u8]> for PhysicalMemoryArray {
             max_capacity,
             error_information_handle,
             number_memory_devices,
+            error: None,
         }
     }
 }
 
 impl PhysicalMemoryArray {
     pub fn render(&self, frame: &mut Frame, block: Rect) {
-        let rows = vec![
+        let mut rows = vec![
             Row::new(vec![
                 Cell::from("Location").bold(),
                 Cell::from(self.location.to_string()),
@@ -227,6 +408,10 @@ impl PhysicalMemoryArray {
                 Cell::from(self.number_memory_devices.to_string()),
             ]),
         ];
+        rows.extend(error_rows(
+            self.error_information_handle,
+            self.error.as_ref(),
+        ));
 
         let widths = [Constraint::Length(30), Constraint::Fill(1)];
         let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
@@ -268,6 +453,8 @@ enum Location {
     Cxl,
 }
 
+serialize_display!(Location, Function, ErrorCorrection);
+
 impl From<u8> for Location {
     fn from(value: u8) -> Self {
         match value {
@@ -366,10 +553,13 @@ fn string_ref(idx: u8, text: &[String]) -> String {
         .unwrap_or_else(|| "Not Specified".to_string())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct MemoryDevice {
+    #[serde(skip)]
+    handle: u16,
     device_locator: String,
     bank_locator: String,
+    #[serde(flatten)]
     size: MemorySize,
     form_factor: FormFactor,
     memory_type: MemoryType,
@@ -382,10 +572,15 @@ pub struct MemoryDevice {
     serial_number: String,
     asset_tag: String,
     part_number: String,
+    // None when not provided, 0xFFFF when no error was detected.
+    #[serde(skip)]
+    error_information_handle: Option<u16>,
+    // Filled in by `Memory::new`.
+    error: Option<MemoryError>,
 }
 
-impl From<(Vec<u8>, Vec<String>)> for MemoryDevice {
-    fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
+impl From<(Vec<u8>, Vec<String>, u16)> for MemoryDevice {
+    fn from((data, text, handle): (Vec<u8>, Vec<String>, u16)) -> Self {
         let size_field = u16::from_le_bytes(data[8..10].try_into().unwrap());
         let extended_size = if data.len() >= 28 {
             Some(u32::from_le_bytes(data[24..28].try_into().unwrap()))
@@ -439,7 +634,11 @@ impl From<(Vec<u8>, Vec<String>)> for MemoryDevice {
             .copied()
             .map_or_else(|| "Not Specified".to_string(), |b| string_ref(b, &text));
 
+        let error_information_handle =
+            Some(u16::from_le_bytes([data[2], data[3]])).filter(|h| *h != 0xFFFE);
+
         Self {
+            handle,
             device_locator: string_ref(data[12], &text),
             bank_locator: string_ref(data[13], &text),
             size,
@@ -454,12 +653,14 @@ impl From<(Vec<u8>, Vec<String>)> for MemoryDevice {
             serial_number,
             asset_tag,
             part_number,
+            error_information_handle,
+            error: None,
         }
     }
 }
 
 impl MemoryDevice {
-    fn render(&self, frame: &mut Frame, block: Rect) {
+    fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
         let speed_text = match self.speed {
             Some(v) => format!("{v} MT/s"),
             None => "Unknown".to_string(),
@@ -477,7 +678,7 @@ impl MemoryDevice {
             None => "Unknown".to_string(),
         };
 
-        let rows = vec![
+        let mut rows = vec![
             Row::new(vec![
                 Cell::from("Size").bold(),
                 Cell::from(self.size.to_string()),
@@ -511,24 +712,58 @@ impl MemoryDevice {
             ]),
             Row::new(vec![
                 Cell::from("Part Number").bold(),
-                Cell::from(self.part_number.clone()),
+                Cell::from(mask_suffix(&self.part_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Serial Number").bold(),
-                Cell::from(self.serial_number.clone()),
+                Cell::from(mask(&self.serial_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Asset Tag").bold(),
-                Cell::from(self.asset_tag.clone()),
+                Cell::from(mask(&self.asset_tag, redact)),
             ]),
         ];
+        rows.extend(error_rows(
+            self.error_information_handle,
+            self.error.as_ref(),
+        ));
 
-        let widths = [Constraint::Length(18), Constraint::Fill(1)];
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
         let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
         frame.render_widget(table, block.inner(Margin::new(2, 0)));
     }
 }
 
+// The memory error an array or device points at, with the details of a
+// recorded error.
+fn error_rows(handle: Option<u16>, error: Option<&MemoryError>) -> Vec<Row<'static>> {
+    let status = match (handle, error) {
+        (None, _) => "Not Provided".to_string(),
+        (Some(0xFFFF), _) => "No error detected".to_string(),
+        (Some(h), None) => format!("Handle {h:#06x} not found"),
+        (Some(_), Some(error)) => error.summary(),
+    };
+    let recorded = error.is_some_and(MemoryError::is_error);
+
+    let mut rows = vec![Row::new(vec![
+        Cell::from("Memory Error").bold(),
+        if recorded {
+            Cell::from(status).red().bold()
+        } else {
+            Cell::from(status)
+        },
+    ])];
+    if let Some(error) = error.filter(|e| e.is_error()) {
+        rows.extend(
+            error
+                .details()
+                .into_iter()
+                .map(|(label, value)| Row::new(vec![Cell::from(label).bold(), Cell::from(value)])),
+        );
+    }
+    rows
+}
+
 fn format_voltage(mv: u16) -> String {
     let s = format!("{:.3}", mv as f64 / 1000.0);
     let trimmed = s.trim_end_matches('0').trim_end_matches('.');
@@ -579,6 +814,23 @@ impl std::fmt::Display for MemorySize {
     }
 }
 
+// Modelled as the display size plus its value in MB (0 when empty, null when
+// unknown), so that exports don't have to parse "32 GB" back.
+impl Serialize for MemorySize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let megabytes = match self {
+            MemorySize::Empty => Some(0),
+            MemorySize::Unknown => None,
+            MemorySize::Megabytes(mb) => Some(*mb),
+        };
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("size", &self.to_string())?;
+        map.serialize_entry("size_mb", &megabytes)?;
+        map.end()
+    }
+}
+
 #[derive(Debug, strum::Display)]
 enum FormFactor {
     #[strum(to_string = "Other")]
@@ -615,6 +867,8 @@ enum FormFactor {
     Die,
 }
 
+serialize_display!(FormFactor, MemoryType, MemoryTechnology);
+
 impl From<u8> for FormFactor {
     fn from(value: u8) -> Self {
         match value {
@@ -0,0 +1,209 @@
+// SMBIOS Type 18 (32-Bit Memory Error Information) and Type 33 (64-Bit
+// Memory Error Information). Spec reference: DSP0134 §7.19, §7.34.
+//
+// Both describe the last error seen by an array or a device, which points at
+// them through its Memory Error Information Handle.
+
+use serde::Serialize;
+
+use crate::dmi::serialize_display;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryError {
+    #[serde(skip)]
+    pub handle: u16,
+    error_type: ErrorType,
+    granularity: Granularity,
+    operation: Operation,
+    vendor_syndrome: Option<u32>,
+    // Null when unknown.
+    array_error_address: Option<u64>,
+    device_error_address: Option<u64>,
+    error_resolution: Option<u32>,
+}
+
+impl MemoryError {
+    // `wide` is set for type 33, whose addresses are 64 bits.
+    pub fn parse(handle: u16, data: &[u8], wide: bool) -> Self {
+        let u32_at = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        };
+        let u64_at = |offset: usize| {
+            data.get(offset..offset + 8)
+                .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+        };
+
+        // The top bit alone means "unknown".
+        let (array_error_address, device_error_address, error_resolution) = if wide {
+            (
+                u64_at(7).filter(|a| *a != 1 << 63),
+                u64_at(15).filter(|a| *a != 1 << 63),
+                u32_at(23),
+            )
+        } else {
+            (
+                u32_at(7).filter(|a| *a != 1 << 31).map(u64::from),
+                u32_at(11).filter(|a| *a != 1 << 31).map(u64::from),
+                u32_at(15),
+            )
+        };
+
+        Self {
+            handle,
+            error_type: ErrorType::from(data[0]),
+            granularity: Granularity::from(data[1]),
+            operation: Operation::from(data[2]),
+            vendor_syndrome: u32_at(3).filter(|s| *s != 0),
+            array_error_address,
+            device_error_address,
+            error_resolution: error_resolution.filter(|r| *r != 1 << 31),
+        }
+    }
+
+    /// Whether this records an actual error, rather than "OK" or "Unknown".
+    pub fn is_error(&self) -> bool {
+        !matches!(self.error_type, ErrorType::Ok | ErrorType::Unknown)
+    }
+
+    pub fn summary(&self) -> String {
+        if !self.is_error() {
+            return self.error_type.to_string();
+        }
+        format!(
+            "{}, {}, {}",
+            self.error_type, self.operation, self.granularity
+        )
+    }
+
+    // Label and value pairs for the fields that are known.
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let mut details = Vec::new();
+        if let Some(address) = self.array_error_address {
+            details.push(("Array Error Address", format!("{address:#x}")));
+        }
+        if let Some(address) = self.device_error_address {
+            details.push(("Device Error Address", format!("{address:#x}")));
+        }
+        if let Some(resolution) = self.error_resolution {
+            details.push(("Error Resolution", format!("{resolution} bytes")));
+        }
+        if let Some(syndrome) = self.vendor_syndrome {
+            details.push(("Vendor Syndrome", format!("{syndrome:#010x}")));
+        }
+        details
+    }
+}
+
+#[derive(Debug, Clone, strum::Display)]
+enum ErrorType {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "OK")]
+    Ok,
+    #[strum(to_string = "Bad read")]
+    BadRead,
+    #[strum(to_string = "Parity error")]
+    Parity,
+    #[strum(to_string = "Single-bit error")]
+    SingleBit,
+    #[strum(to_string = "Double-bit error")]
+    DoubleBit,
+    #[strum(to_string = "Multi-bit error")]
+    MultiBit,
+    #[strum(to_string = "Nibble error")]
+    Nibble,
+    #[strum(to_string = "Checksum error")]
+    Checksum,
+    #[strum(to_string = "CRC error")]
+    Crc,
+    #[strum(to_string = "Corrected single-bit error")]
+    CorrectedSingleBit,
+    #[strum(to_string = "Corrected error")]
+    Corrected,
+    #[strum(to_string = "Uncorrectable error")]
+    Uncorrectable,
+    #[strum(to_string = "Error type {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for ErrorType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Ok,
+            0x04 => Self::BadRead,
+            0x05 => Self::Parity,
+            0x06 => Self::SingleBit,
+            0x07 => Self::DoubleBit,
+            0x08 => Self::MultiBit,
+            0x09 => Self::Nibble,
+            0x0A => Self::Checksum,
+            0x0B => Self::Crc,
+            0x0C => Self::CorrectedSingleBit,
+            0x0D => Self::Corrected,
+            0x0E => Self::Uncorrectable,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, strum::Display)]
+enum Granularity {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "Device level")]
+    Device,
+    #[strum(to_string = "Memory partition level")]
+    Partition,
+    #[strum(to_string = "Granularity {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for Granularity {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Device,
+            0x04 => Self::Partition,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, strum::Display)]
+enum Operation {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "Read")]
+    Read,
+    #[strum(to_string = "Write")]
+    Write,
+    #[strum(to_string = "Partial write")]
+    PartialWrite,
+    #[strum(to_string = "Operation {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for Operation {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Read,
+            0x04 => Self::Write,
+            0x05 => Self::PartialWrite,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+serialize_display!(ErrorType, Granularity, Operation);
@@ -0,0 +1,207 @@
+// SMBIOS Type 11 (OEM Strings) and Type 12 (System Configuration Options).
+// Spec reference: DSP0134 §7.12, §7.13.
+//
+// Both structures are nothing but a counted list of strings, so they share a
+// section. Strings can be filtered with a search and copied to the clipboard
+// through the terminal (OSC 52), which also works over SSH. Vendors often put
+// serial numbers and UUIDs in them, so every string is masked when redacting.
+use std::io::{self, Write};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Cell, Row, Table, TableState},
+};
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+use crate::dmi::mask;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    OemString,
+    ConfigurationOption,
+}
+
+#[derive(Debug)]
+struct Entry {
+    source: Source,
+    index: usize,
+    value: String,
+}
+
+#[derive(Debug, Default)]
+pub struct OemStrings {
+    entries: Vec<Entry>,
+    selected: usize,
+    query: String,
+    // Whether keys go to the search field.
+    pub editing: bool,
+    status: Option<String>,
+}
+
+impl OemStrings {
+    pub fn push_oem_strings(&mut self, data: &[u8], text: &[String]) {
+        self.push(Source::OemString, data, text);
+    }
+
+    pub fn push_configuration_options(&mut self, data: &[u8], text: &[String]) {
+        self.push(Source::ConfigurationOption, data, text);
+    }
+
+    // The formatted area only holds the number of strings.
+    fn push(&mut self, source: Source, data: &[u8], text: &[String]) {
+        let count = data.first().copied().unwrap_or(0) as usize;
+        self.entries
+            .extend(text.iter().take(count).enumerate().map(|(i, value)| Entry {
+                source,
+                index: i + 1,
+                value: value.clone(),
+            }));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Searches what is on screen, so a redacted string can't be guessed at.
+    fn visible(&self, redact: bool) -> Vec<&Entry> {
+        let query = self.query.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|e| mask(&e.value, redact).to_lowercase().contains(&query))
+            .collect()
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent, redact: bool) {
+        self.status = None;
+
+        if self.editing {
+            match key_event.code {
+                KeyCode::Char(c) => self.query.push(c),
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Enter => self.editing = false,
+                KeyCode::Esc => {
+                    self.query.clear();
+                    self.editing = false;
+                }
+                _ => {}
+            }
+            self.selected = 0;
+            return;
+        }
+
+        let count = self.visible(redact).len();
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') if count > 0 => {
+                self.selected = (self.selected + 1) % count;
+            }
+            KeyCode::Up | KeyCode::Char('k') if count > 0 => {
+                self.selected = (self.selected + count - 1) % count;
+            }
+            KeyCode::Char('/') => self.editing = true,
+            KeyCode::Esc => {
+                self.query.clear();
+                self.selected = 0;
+            }
+            KeyCode::Char('c') => {
+                let copied = self.visible(redact).get(self.selected).map(|e| {
+                    let label = match e.source {
+                        Source::OemString => "OEM string",
+                        Source::ConfigurationOption => "option",
+                    };
+                    (format!("{label} {}", e.index), mask(&e.value, redact))
+                });
+                if let Some((label, value)) = copied {
+                    self.status = Some(match copy(&value) {
+                        Ok(()) => format!("Copied {label}"),
+                        Err(e) => format!("Copy failed: {e}"),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let mut search = vec![
+            Span::from("Search: ").bold(),
+            Span::from(self.query.clone()),
+        ];
+        if self.editing {
+            search.push(Span::from("▏"));
+        }
+        if let Some(status) = &self.status {
+            search.push(Span::from(format!("    {status}")).green());
+        }
+        frame.render_widget(Line::from(search), chunks[0]);
+
+        let rows: Vec<Row<'_>> = self
+            .visible(redact)
+            .into_iter()
+            .map(|e| {
+                let source = match e.source {
+                    Source::OemString => "OEM String",
+                    Source::ConfigurationOption => "Configuration Option",
+                };
+                Row::new(vec![
+                    Cell::from(source),
+                    Cell::from(e.index.to_string()),
+                    Cell::from(mask(&e.value, redact)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(22),
+            Constraint::Length(4),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Source", "#", "Value"])
+                    .bold()
+                    .bottom_margin(1),
+            )
+            .column_spacing(2)
+            .row_highlight_style(Style::new().bold().reversed());
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, chunks[1], &mut state);
+    }
+}
+
+impl Serialize for OemStrings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let values = |source| -> Vec<&str> {
+            self.entries
+                .iter()
+                .filter(|e| e.source == source)
+                .map(|e| e.value.as_str())
+                .collect()
+        };
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("strings", &values(Source::OemString))?;
+        map.serialize_entry(
+            "configuration_options",
+            &values(Source::ConfigurationOption),
+        )?;
+        map.end()
+    }
+}
+
+// Asks the terminal to put `value` on the system clipboard.
+fn copy(value: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(value))?;
+    stdout.flush()
+}
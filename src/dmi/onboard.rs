@@ -0,0 +1,321 @@
+// SMBIOS Type 10 (On Board Devices Information, obsolete) and Type 41
+// (Onboard Devices Extended Information). Spec reference: DSP0134 §7.11, §7.42.
+use std::{fs, path::Path};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
+};
+use serde::{Serialize, Serializer};
+
+use crate::dmi::serialize_display;
+use crate::dmi::slot::BusDeviceFunction;
+
+const SYSFS_PCI_DEVICES: &str = "/sys/bus/pci/devices";
+
+fn string_ref(idx: u8, text: &[String]) -> String {
+    if idx == 0 {
+        return "Not Specified".to_string();
+    }
+    text.get((idx - 1) as usize)
+        .cloned()
+        .unwrap_or_else(|| "Not Specified".to_string())
+}
+
+#[derive(Debug)]
+pub struct OnboardDevices {
+    list: Vec<OnboardDevice>,
+    selected: usize,
+}
+
+impl OnboardDevices {
+    pub fn new(list: Vec<OnboardDevice>) -> Option<Self> {
+        if list.is_empty() {
+            None
+        } else {
+            Some(Self { list, selected: 0 })
+        }
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.list.len() >= 2
+    }
+
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
+    /// Looks up the network interface and driver bound to every device's
+    /// BDF. Only meaningful when the table was read from the running system.
+    pub fn link_sysfs(&mut self) {
+        for device in &mut self.list {
+            let Some(bdf) = &device.bdf else {
+                continue;
+            };
+            let path = Path::new(SYSFS_PCI_DEVICES).join(bdf.to_string());
+
+            device.interfaces = interfaces(&path);
+            device.driver = fs::read_link(path.join("driver"))
+                .ok()
+                .and_then(|target| target.file_name().map(|n| n.to_string_lossy().to_string()));
+        }
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        if !self.has_multiple() {
+            return;
+        }
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % self.list.len();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + self.list.len() - 1) % self.list.len();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, block: Rect) {
+        if !self.has_multiple() {
+            self.list[0].render(frame, block);
+            return;
+        }
+
+        let max_label = self
+            .list
+            .iter()
+            .map(|d| d.reference_designation.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        // 4 = 2 borders + 2 horizontal padding
+        let list_width = max_label.saturating_add(4).max(14);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let items: Vec<ListItem<'_>> = self
+            .list
+            .iter()
+            .map(|d| ListItem::new(d.reference_designation.clone()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 1, 0)),
+            )
+            .highlight_style(Style::new().bold().reversed())
+            .highlight_symbol("");
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, body[0], &mut state);
+
+        if let Some(device) = self.list.get(self.selected) {
+            device.render(frame, body[1]);
+        }
+    }
+}
+
+// Interface names under the device's `net` directory, or under that of a
+// child device, as with virtio-net (`0000:00:04.0/virtio3/net/eth0`).
+fn interfaces(device: &Path) -> Vec<String> {
+    let names = |dir: &Path| -> Vec<String> {
+        fs::read_dir(dir.join("net"))
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let mut found = names(device);
+    if found.is_empty()
+        && let Ok(children) = fs::read_dir(device)
+    {
+        for child in children.filter_map(|e| e.ok()) {
+            if child.file_type().is_ok_and(|t| t.is_dir()) {
+                found.extend(names(&child.path()));
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+impl Serialize for OnboardDevices {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OnboardDevice {
+    reference_designation: String,
+    device_type: DeviceType,
+    enabled: bool,
+    // Type 41 only.
+    instance: Option<u8>,
+    bdf: Option<BusDeviceFunction>,
+    // Filled in from sysfs by `OnboardDevices::link_sysfs`.
+    interfaces: Vec<String>,
+    driver: Option<String>,
+}
+
+impl OnboardDevice {
+    // Type 10 packs any number of two byte entries in one structure: the
+    // device type with the enabled bit, then a description string.
+    pub fn parse_legacy(data: &[u8], text: &[String]) -> Vec<Self> {
+        data.chunks_exact(2)
+            .map(|entry| Self {
+                reference_designation: string_ref(entry[1], text),
+                device_type: DeviceType::from(entry[0] & 0x7F),
+                enabled: entry[0] & 0x80 != 0,
+                instance: None,
+                bdf: None,
+                interfaces: Vec::new(),
+                driver: None,
+            })
+            .collect()
+    }
+
+    fn render(&self, frame: &mut Frame, block: Rect) {
+        let mut rows = vec![
+            Row::new(vec![
+                Cell::from("Designation").bold(),
+                Cell::from(self.reference_designation.clone()),
+            ]),
+            Row::new(vec![
+                Cell::from("Type").bold(),
+                Cell::from(self.device_type.to_string()),
+            ]),
+            Row::new(vec![
+                Cell::from("Status").bold(),
+                Cell::from(if self.enabled { "Enabled" } else { "Disabled" }),
+            ]),
+        ];
+        if let Some(instance) = self.instance {
+            rows.push(Row::new(vec![
+                Cell::from("Type Instance").bold(),
+                Cell::from(instance.to_string()),
+            ]));
+        }
+        if let Some(bdf) = &self.bdf {
+            rows.push(Row::new(vec![
+                Cell::from("Bus:Device.Function").bold(),
+                Cell::from(bdf.to_string()),
+            ]));
+        }
+        if !self.interfaces.is_empty() {
+            rows.push(Row::new(vec![
+                Cell::from("Interface").bold(),
+                Cell::from(self.interfaces.join(", ")),
+            ]));
+        }
+        if let Some(driver) = &self.driver {
+            rows.push(Row::new(vec![
+                Cell::from("Driver").bold(),
+                Cell::from(driver.clone()),
+            ]));
+        }
+
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
+        frame.render_widget(table, block.inner(Margin::new(2, 0)));
+    }
+}
+
+impl From<(Vec<u8>, Vec<String>)> for OnboardDevice {
+    fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
+        let bdf = data
+            .get(3..7)
+            .and_then(|b| BusDeviceFunction::new(u16::from_le_bytes([b[0], b[1]]), b[2], b[3]));
+
+        Self {
+            reference_designation: string_ref(data[0], &text),
+            device_type: DeviceType::from(data[1] & 0x7F),
+            enabled: data[1] & 0x80 != 0,
+            instance: Some(data[2]),
+            bdf,
+            interfaces: Vec::new(),
+            driver: None,
+        }
+    }
+}
+
+#[derive(Debug, strum::Display)]
+enum DeviceType {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "Video")]
+    Video,
+    #[strum(to_string = "SCSI Controller")]
+    Scsi,
+    #[strum(to_string = "Ethernet")]
+    Ethernet,
+    #[strum(to_string = "Token Ring")]
+    TokenRing,
+    #[strum(to_string = "Sound")]
+    Sound,
+    #[strum(to_string = "PATA Controller")]
+    Pata,
+    #[strum(to_string = "SATA Controller")]
+    Sata,
+    #[strum(to_string = "SAS Controller")]
+    Sas,
+    #[strum(to_string = "Wireless LAN")]
+    WirelessLan,
+    #[strum(to_string = "Bluetooth")]
+    Bluetooth,
+    #[strum(to_string = "WWAN")]
+    Wwan,
+    #[strum(to_string = "eMMC")]
+    Emmc,
+    #[strum(to_string = "NVMe Controller")]
+    Nvme,
+    #[strum(to_string = "UFS Controller")]
+    Ufs,
+    #[strum(to_string = "Device type {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for DeviceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Video,
+            0x04 => Self::Scsi,
+            0x05 => Self::Ethernet,
+            0x06 => Self::TokenRing,
+            0x07 => Self::Sound,
+            0x08 => Self::Pata,
+            0x09 => Self::Sata,
+            0x0A => Self::Sas,
+            0x0B => Self::WirelessLan,
+            0x0C => Self::Bluetooth,
+            0x0D => Self::Wwan,
+            0x0E => Self::Emmc,
+            0x0F => Self::Nvme,
+            0x10 => Self::Ufs,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+serialize_display!(DeviceType);
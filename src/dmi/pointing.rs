@@ -0,0 +1,236 @@
+// SMBIOS Type 21 (Built-in Pointing Device). Spec reference: DSP0134 §7.22.
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
+};
+use serde::{Serialize, Serializer};
+
+use crate::dmi::serialize_display;
+
+#[derive(Debug)]
+pub struct PointingDevices {
+    list: Vec<PointingDevice>,
+    selected: usize,
+}
+
+impl PointingDevices {
+    pub fn new(list: Vec<PointingDevice>) -> Option<Self> {
+        if list.is_empty() {
+            None
+        } else {
+            Some(Self { list, selected: 0 })
+        }
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.list.len() >= 2
+    }
+
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        if !self.has_multiple() {
+            return;
+        }
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % self.list.len();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + self.list.len() - 1) % self.list.len();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, block: Rect) {
+        if !self.has_multiple() {
+            self.list[0].render(frame, block);
+            return;
+        }
+
+        let labels: Vec<String> = self
+            .list
+            .iter()
+            .map(|d| d.device_type.to_string())
+            .collect();
+        let max_label = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16;
+        // 4 = 2 borders + 2 horizontal padding
+        let list_width = max_label.saturating_add(4).max(14);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let items: Vec<ListItem<'_>> = labels.into_iter().map(ListItem::new).collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 1, 0)),
+            )
+            .highlight_style(Style::new().bold().reversed())
+            .highlight_symbol("");
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, body[0], &mut state);
+
+        if let Some(device) = self.list.get(self.selected) {
+            device.render(frame, body[1]);
+        }
+    }
+}
+
+impl Serialize for PointingDevices {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PointingDevice {
+    device_type: DeviceType,
+    interface: Interface,
+    buttons: u8,
+}
+
+impl From<(Vec<u8>, Vec<String>)> for PointingDevice {
+    fn from((data, _text): (Vec<u8>, Vec<String>)) -> Self {
+        Self {
+            device_type: DeviceType::from(data[0]),
+            interface: Interface::from(data[1]),
+            buttons: data[2],
+        }
+    }
+}
+
+impl PointingDevice {
+    fn render(&self, frame: &mut Frame, block: Rect) {
+        let rows = vec![
+            Row::new(vec![
+                Cell::from("Type").bold(),
+                Cell::from(self.device_type.to_string()),
+            ]),
+            Row::new(vec![
+                Cell::from("Interface").bold(),
+                Cell::from(self.interface.to_string()),
+            ]),
+            Row::new(vec![
+                Cell::from("Buttons").bold(),
+                Cell::from(self.buttons.to_string()),
+            ]),
+        ];
+
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
+        frame.render_widget(table, block.inner(Margin::new(2, 0)));
+    }
+}
+
+#[derive(Debug, strum::Display)]
+enum DeviceType {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "Mouse")]
+    Mouse,
+    #[strum(to_string = "Track Ball")]
+    TrackBall,
+    #[strum(to_string = "Track Point")]
+    TrackPoint,
+    #[strum(to_string = "Glide Point")]
+    GlidePoint,
+    #[strum(to_string = "Touch Pad")]
+    TouchPad,
+    #[strum(to_string = "Touch Screen")]
+    TouchScreen,
+    #[strum(to_string = "Optical Sensor")]
+    OpticalSensor,
+    #[strum(to_string = "Device type {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for DeviceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Mouse,
+            0x04 => Self::TrackBall,
+            0x05 => Self::TrackPoint,
+            0x06 => Self::GlidePoint,
+            0x07 => Self::TouchPad,
+            0x08 => Self::TouchScreen,
+            0x09 => Self::OpticalSensor,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+#[derive(Debug, strum::Display)]
+enum Interface {
+    #[strum(to_string = "Other")]
+    Other,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+    #[strum(to_string = "Serial")]
+    Serial,
+    #[strum(to_string = "PS/2")]
+    Ps2,
+    #[strum(to_string = "Infrared")]
+    Infrared,
+    #[strum(to_string = "HP-HIL")]
+    HpHil,
+    #[strum(to_string = "Bus mouse")]
+    BusMouse,
+    #[strum(to_string = "ADB (Apple Desktop Bus)")]
+    Adb,
+    #[strum(to_string = "Bus mouse DB-9")]
+    BusMouseDb9,
+    #[strum(to_string = "Bus mouse micro DIN")]
+    BusMouseMicroDin,
+    #[strum(to_string = "USB")]
+    Usb,
+    #[strum(to_string = "I2C")]
+    I2c,
+    #[strum(to_string = "SPI")]
+    Spi,
+    #[strum(to_string = "Interface {0:#x}")]
+    Reserved(u8),
+}
+
+impl From<u8> for Interface {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Other,
+            0x02 => Self::Unknown,
+            0x03 => Self::Serial,
+            0x04 => Self::Ps2,
+            0x05 => Self::Infrared,
+            0x06 => Self::HpHil,
+            0x07 => Self::BusMouse,
+            0x08 => Self::Adb,
+            0xA0 => Self::BusMouseDb9,
+            0xA1 => Self::BusMouseMicroDin,
+            0xA2 => Self::Usb,
+            0xA3 => Self::I2c,
+            0xA4 => Self::Spi,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+serialize_display!(DeviceType, Interface);
@@ -0,0 +1,304 @@
+// SMBIOS Type 8 (Port Connector Information). Spec reference: DSP0134 §7.9.
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
+};
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+fn string_ref(idx: u8, text: &[String]) -> String {
+    if idx == 0 {
+        return "Not Specified".to_string();
+    }
+    text.get((idx - 1) as usize)
+        .cloned()
+        .unwrap_or_else(|| "Not Specified".to_string())
+}
+
+#[derive(Debug)]
+pub struct Ports {
+    list: Vec<Port>,
+    selected: usize,
+}
+
+impl Ports {
+    pub fn new(list: Vec<Port>) -> Option<Self> {
+        if list.is_empty() {
+            None
+        } else {
+            Some(Self { list, selected: 0 })
+        }
+    }
+
+    pub fn has_multiple(&self) -> bool {
+        self.list.len() >= 2
+    }
+
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) {
+        if !self.has_multiple() {
+            return;
+        }
+        match key_event.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % self.list.len();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + self.list.len() - 1) % self.list.len();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, block: Rect) {
+        if !self.has_multiple() {
+            self.list[0].render(frame, block);
+            return;
+        }
+
+        let max_label = self
+            .list
+            .iter()
+            .map(|p| p.name().chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        // 4 = 2 borders + 2 horizontal padding
+        let list_width = max_label.saturating_add(4).max(14);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(list_width), Constraint::Fill(1)])
+            .split(block.inner(Margin::new(4, 2)));
+
+        let items: Vec<ListItem<'_>> = self
+            .list
+            .iter()
+            .map(|p| ListItem::new(p.name().to_string()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 1, 0)),
+            )
+            .highlight_style(Style::new().bold().reversed())
+            .highlight_symbol("");
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, body[0], &mut state);
+
+        if let Some(port) = self.list.get(self.selected) {
+            port.render(frame, body[1]);
+        }
+    }
+}
+
+impl Serialize for Ports {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
+#[derive(Debug)]
+pub struct Port {
+    internal_reference_designator: String,
+    internal_connector_type: u8,
+    external_reference_designator: String,
+    external_connector_type: u8,
+    port_type: u8,
+}
+
+impl Serialize for Port {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Port", 5)?;
+        state.serialize_field(
+            "internal_reference_designator",
+            &self.internal_reference_designator,
+        )?;
+        state.serialize_field(
+            "internal_connector_type",
+            &connector_type_name(self.internal_connector_type),
+        )?;
+        state.serialize_field(
+            "external_reference_designator",
+            &self.external_reference_designator,
+        )?;
+        state.serialize_field(
+            "external_connector_type",
+            &connector_type_name(self.external_connector_type),
+        )?;
+        state.serialize_field("port_type", &port_type_name(self.port_type))?;
+        state.end()
+    }
+}
+
+impl From<(Vec<u8>, Vec<String>)> for Port {
+    fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
+        Self {
+            internal_reference_designator: string_ref(data[0], &text),
+            internal_connector_type: data[1],
+            external_reference_designator: string_ref(data[2], &text),
+            external_connector_type: data[3],
+            port_type: data[4],
+        }
+    }
+}
+
+impl Port {
+    // Rear-panel ports are known by their external designator, headers on the
+    // board by their internal one.
+    fn name(&self) -> &str {
+        if self.external_reference_designator != "Not Specified" {
+            &self.external_reference_designator
+        } else {
+            &self.internal_reference_designator
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, block: Rect) {
+        let rows = vec![
+            Row::new(vec![
+                Cell::from("Port Type").bold(),
+                Cell::from(port_type_name(self.port_type)),
+            ]),
+            Row::new(vec![
+                Cell::from("Internal Designator").bold(),
+                Cell::from(self.internal_reference_designator.clone()),
+            ]),
+            Row::new(vec![
+                Cell::from("Internal Connector").bold(),
+                Cell::from(connector_type_name(self.internal_connector_type)),
+            ]),
+            Row::new(vec![
+                Cell::from("External Designator").bold(),
+                Cell::from(self.external_reference_designator.clone()),
+            ]),
+            Row::new(vec![
+                Cell::from("External Connector").bold(),
+                Cell::from(connector_type_name(self.external_connector_type)),
+            ]),
+        ];
+
+        let widths = [Constraint::Length(22), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
+        frame.render_widget(table, block.inner(Margin::new(2, 0)));
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.9.2.
+const CONNECTOR_TYPE: &[&str] = &[
+    "None",                             // 0x00
+    "Centronics",                       // 0x01
+    "Mini Centronics",                  // 0x02
+    "Proprietary",                      // 0x03
+    "DB-25 male",                       // 0x04
+    "DB-25 female",                     // 0x05
+    "DB-15 male",                       // 0x06
+    "DB-15 female",                     // 0x07
+    "DB-9 male",                        // 0x08
+    "DB-9 female",                      // 0x09
+    "RJ-11",                            // 0x0A
+    "RJ-45",                            // 0x0B
+    "50 Pin MiniSCSI",                  // 0x0C
+    "Mini DIN",                         // 0x0D
+    "Micro DIN",                        // 0x0E
+    "PS/2",                             // 0x0F
+    "Infrared",                         // 0x10
+    "HP-HIL",                           // 0x11
+    "Access Bus (USB)",                 // 0x12
+    "SSA SCSI",                         // 0x13
+    "Circular DIN-8 male",              // 0x14
+    "Circular DIN-8 female",            // 0x15
+    "On Board IDE",                     // 0x16
+    "On Board Floppy",                  // 0x17
+    "9 Pin Dual Inline (pin 10 cut)",   // 0x18
+    "25 Pin Dual Inline (pin 26 cut)",  // 0x19
+    "50 Pin Dual Inline",               // 0x1A
+    "68 Pin Dual Inline",               // 0x1B
+    "On Board Sound Input From CD-ROM", // 0x1C
+    "Mini Centronics Type-14",          // 0x1D
+    "Mini Centronics Type-26",          // 0x1E
+    "Mini Jack (headphones)",           // 0x1F
+    "BNC",                              // 0x20
+    "IEEE 1394",                        // 0x21
+    "SAS/SATA Plug Receptacle",         // 0x22
+    "USB Type-C Receptacle",            // 0x23
+];
+
+const CONNECTOR_TYPE_PC98: &[&str] = &[
+    "PC-98",        // 0xA0
+    "PC-98 Hireso", // 0xA1
+    "PC-H98",       // 0xA2
+    "PC-98 Note",   // 0xA3
+    "PC-98 Full",   // 0xA4
+];
+
+fn connector_type_name(code: u8) -> String {
+    match code {
+        0x00..=0x23 => CONNECTOR_TYPE[code as usize].to_string(),
+        0xA0..=0xA4 => CONNECTOR_TYPE_PC98[(code - 0xA0) as usize].to_string(),
+        0xFF => "Other".to_string(),
+        _ => format!("Connector type {code:#x}"),
+    }
+}
+
+// Spec reference: SMBIOS DSP0134 §7.9.3.
+const PORT_TYPE: &[&str] = &[
+    "None",                               // 0x00
+    "Parallel Port XT/AT Compatible",     // 0x01
+    "Parallel Port PS/2",                 // 0x02
+    "Parallel Port ECP",                  // 0x03
+    "Parallel Port EPP",                  // 0x04
+    "Parallel Port ECP/EPP",              // 0x05
+    "Serial Port XT/AT Compatible",       // 0x06
+    "Serial Port 16450 Compatible",       // 0x07
+    "Serial Port 16550 Compatible",       // 0x08
+    "Serial Port 16550A Compatible",      // 0x09
+    "SCSI Port",                          // 0x0A
+    "MIDI Port",                          // 0x0B
+    "Joystick Port",                      // 0x0C
+    "Keyboard Port",                      // 0x0D
+    "Mouse Port",                         // 0x0E
+    "SSA SCSI",                           // 0x0F
+    "USB",                                // 0x10
+    "Firewire (IEEE P1394)",              // 0x11
+    "PCMCIA Type I",                      // 0x12
+    "PCMCIA Type II",                     // 0x13
+    "PCMCIA Type III",                    // 0x14
+    "Cardbus",                            // 0x15
+    "Access Bus Port",                    // 0x16
+    "SCSI II",                            // 0x17
+    "SCSI Wide",                          // 0x18
+    "PC-98",                              // 0x19
+    "PC-98 Hireso",                       // 0x1A
+    "PC-H98",                             // 0x1B
+    "Video Port",                         // 0x1C
+    "Audio Port",                         // 0x1D
+    "Modem Port",                         // 0x1E
+    "Network Port",                       // 0x1F
+    "SATA",                               // 0x20
+    "SAS",                                // 0x21
+    "MFDP (Multi-Function Display Port)", // 0x22
+    "Thunderbolt",                        // 0x23
+];
+
+fn port_type_name(code: u8) -> String {
+    match code {
+        0x00..=0x23 => PORT_TYPE[code as usize].to_string(),
+        0xA0 => "8251 Compatible".to_string(),
+        0xA1 => "8251 FIFO Compatible".to_string(),
+        0xFF => "Other".to_string(),
+        _ => format!("Port type {code:#x}"),
+    }
+}
@@ -5,8 +5,10 @@ use ratatui::{
     style::{Style, Stylize},
     widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
 };
+use serde::{Serialize, Serializer};
 
 use crate::dmi::cache::Cache;
+use crate::dmi::{mask, mask_suffix, serialize_display};
 
 fn string_ref(idx: u8, text: &[String]) -> String {
     if idx == 0 {
@@ -41,6 +43,13 @@ impl Processors {
         self.list.len() >= 2
     }
 
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
     pub fn handle_key_events(&mut self, key_event: KeyEvent) {
         if !self.has_multiple() {
             return;
@@ -56,9 +65,9 @@ impl Processors {
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, block: Rect) {
+    pub fn render(&mut self, frame: &mut Frame, block: Rect, redact: bool) {
         if !self.has_multiple() {
-            self.list[0].render(frame, block, &self.caches);
+            self.list[0].render(frame, block, &self.caches, redact);
             return;
         }
 
@@ -97,15 +106,43 @@ impl Processors {
         frame.render_stateful_widget(list, body[0], &mut state);
 
         if let Some(processor) = self.list.get(self.selected) {
-            processor.render(frame, body[1], &self.caches);
+            processor.render(frame, body[1], &self.caches, redact);
         }
     }
 }
 
-#[derive(Debug)]
+// Processors are modelled as a plain list, with the family decoded against the
+// manufacturer and each cache handle resolved to the Cache it points at.
+impl Serialize for Processors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let find_cache =
+            |handle: Option<u16>| handle.and_then(|h| self.caches.iter().find(|c| c.handle == h));
+
+        serializer.collect_seq(self.list.iter().map(|processor| ProcessorEntry {
+            processor,
+            family: family_name(processor.family, &processor.manufacturer),
+            l1_cache: find_cache(processor.l1_cache),
+            l2_cache: find_cache(processor.l2_cache),
+            l3_cache: find_cache(processor.l3_cache),
+        }))
+    }
+}
+
+#[derive(Serialize)]
+struct ProcessorEntry<'a> {
+    #[serde(flatten)]
+    processor: &'a Processor,
+    family: String,
+    l1_cache: Option<&'a Cache>,
+    l2_cache: Option<&'a Cache>,
+    l3_cache: Option<&'a Cache>,
+}
+
+#[derive(Debug, Serialize)]
 pub struct Processor {
     socket_designation: String,
     processor_type: ProcessorType,
+    #[serde(skip)]
     family: u16,
     manufacturer: String,
     version: String,
@@ -113,13 +150,18 @@ pub struct Processor {
     max_speed: Option<u16>,
     current_speed: Option<u16>,
     status: ProcessorStatus,
+    #[serde(serialize_with = "serialize_upgrade")]
     upgrade: u8,
+    #[serde(skip)]
     l1_cache: Option<u16>,
+    #[serde(skip)]
     l2_cache: Option<u16>,
+    #[serde(skip)]
     l3_cache: Option<u16>,
     core_count: Option<u16>,
     core_enabled: Option<u16>,
     thread_count: Option<u16>,
+    characteristics: Option<ProcessorCharacteristics>,
     serial_number: String,
     asset_tag: String,
     part_number: String,
@@ -162,6 +204,10 @@ impl From<(Vec<u8>, Vec<String>)> for Processor {
             .copied()
             .map_or_else(|| "Not Specified".to_string(), |b| string_ref(b, &text));
 
+        let characteristics = data
+            .get(34..36)
+            .map(|s| ProcessorCharacteristics::from(u16::from_le_bytes([s[0], s[1]])));
+
         let l1_cache = cache_handle(&data, 22);
         let l2_cache = cache_handle(&data, 24);
         let l3_cache = cache_handle(&data, 26);
@@ -183,6 +229,7 @@ impl From<(Vec<u8>, Vec<String>)> for Processor {
             core_count,
             core_enabled,
             thread_count,
+            characteristics,
             serial_number,
             asset_tag,
             part_number,
@@ -210,7 +257,7 @@ fn read_count(legacy: Option<u8>, extended: Option<&[u8]>) -> Option<u16> {
 }
 
 impl Processor {
-    fn render(&self, frame: &mut Frame, block: Rect, caches: &[Cache]) {
+    fn render(&self, frame: &mut Frame, block: Rect, caches: &[Cache], redact: bool) {
         let speed_cell = |v: Option<u16>| match v {
             Some(s) => format!("{s} MHz"),
             None => "Unknown".to_string(),
@@ -268,6 +315,14 @@ impl Processor {
                 Cell::from("Threads").bold(),
                 Cell::from(count_cell(self.thread_count)),
             ]),
+            Row::new(vec![
+                Cell::from("Characteristics").bold(),
+                Cell::from(
+                    self.characteristics
+                        .as_ref()
+                        .map_or_else(|| "Unknown".to_string(), |c| c.to_string()),
+                ),
+            ]),
             Row::new(vec![
                 Cell::from("Voltage").bold(),
                 Cell::from(self.voltage.to_string()),
@@ -285,15 +340,15 @@ impl Processor {
             cache_row("L3 Cache", self.l3_cache),
             Row::new(vec![
                 Cell::from("Part Number").bold(),
-                Cell::from(self.part_number.clone()),
+                Cell::from(mask_suffix(&self.part_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Serial Number").bold(),
-                Cell::from(self.serial_number.clone()),
+                Cell::from(mask(&self.serial_number, redact)),
             ]),
             Row::new(vec![
                 Cell::from("Asset Tag").bold(),
-                Cell::from(self.asset_tag.clone()),
+                Cell::from(mask(&self.asset_tag, redact)),
             ]),
         ];
 
@@ -332,6 +387,61 @@ impl From<u8> for ProcessorType {
     }
 }
 
+serialize_display!(ProcessorType, ProcessorStatus, VoltageInfo);
+
+#[derive(Debug, Serialize)]
+struct ProcessorCharacteristics {
+    #[serde(rename = "64_bit_capable")]
+    _64_bit_capable: bool,
+    multi_core: bool,
+    hardware_thread: bool,
+    execute_protection: bool,
+    enhanced_virtualization: bool,
+    power_performance_control: bool,
+    #[serde(rename = "128_bit_capable")]
+    _128_bit_capable: bool,
+    arm64_soc_id: bool,
+}
+
+impl From<u16> for ProcessorCharacteristics {
+    fn from(bits: u16) -> Self {
+        Self {
+            _64_bit_capable: bits & (1 << 2) != 0,
+            multi_core: bits & (1 << 3) != 0,
+            hardware_thread: bits & (1 << 4) != 0,
+            execute_protection: bits & (1 << 5) != 0,
+            enhanced_virtualization: bits & (1 << 6) != 0,
+            power_performance_control: bits & (1 << 7) != 0,
+            _128_bit_capable: bits & (1 << 8) != 0,
+            arm64_soc_id: bits & (1 << 9) != 0,
+        }
+    }
+}
+
+impl std::fmt::Display for ProcessorCharacteristics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = [
+            (self._64_bit_capable, "64-bit"),
+            (self.multi_core, "Multi-Core"),
+            (self.hardware_thread, "Hardware Thread"),
+            (self.execute_protection, "Execute Protection"),
+            (self.enhanced_virtualization, "Virtualization"),
+            (self.power_performance_control, "Power/Performance Control"),
+            (self._128_bit_capable, "128-bit"),
+            (self.arm64_soc_id, "Arm64 SoC ID"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect();
+
+        if names.is_empty() {
+            write!(f, "None")
+        } else {
+            write!(f, "{}", names.join(", "))
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ProcessorStatus {
     populated: bool,
@@ -762,3 +872,7 @@ fn upgrade_name(upgrade: u8) -> String {
     }
     UPGRADE_NAMES[(upgrade - 1) as usize].to_string()
 }
+
+fn serialize_upgrade<S: Serializer>(upgrade: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&upgrade_name(*upgrade))
+}
@@ -0,0 +1,238 @@
+// SMBIOS Type 23 (System Reset) and Type 24 (Hardware Security). Spec
+// reference: DSP0134 §7.24, §7.25.
+//
+// Shown together with the security status of the chassis (type 3), as the
+// settings a security audit asks about.
+use ratatui::{
+    Frame,
+    layout::{Constraint, Margin, Rect},
+    style::Stylize,
+    widgets::{Block, Cell, Padding, Row, Table},
+};
+use serde::Serialize;
+
+use crate::dmi::chassis::SecurityStatus;
+use crate::dmi::serialize_display;
+
+#[derive(Debug, Serialize)]
+pub struct Security {
+    hardware_security: Option<HardwareSecurity>,
+    system_reset: Option<SystemReset>,
+    chassis_security_status: Option<SecurityStatus>,
+}
+
+impl Security {
+    // Only when the firmware reports type 23 or 24: the chassis status alone
+    // is already in the Chassis section.
+    pub fn new(
+        hardware_security: Option<HardwareSecurity>,
+        system_reset: Option<SystemReset>,
+        chassis_security_status: Option<SecurityStatus>,
+    ) -> Option<Self> {
+        if hardware_security.is_none() && system_reset.is_none() {
+            return None;
+        }
+        Some(Self {
+            hardware_security,
+            system_reset,
+            chassis_security_status,
+        })
+    }
+
+    pub fn render(&self, frame: &mut Frame, block: Rect) {
+        let mut rows = Vec::new();
+        let heading = |title: &'static str| Row::new(vec![Cell::from(title).bold().underlined()]);
+        let row = |label: &'static str, value: String, insecure: bool| {
+            Row::new(vec![
+                Cell::from(label).bold(),
+                if insecure {
+                    Cell::from(value).red().bold()
+                } else {
+                    Cell::from(value)
+                },
+            ])
+        };
+
+        if let Some(hardware) = &self.hardware_security {
+            rows.push(heading("Hardware Security"));
+            for (label, status) in [
+                ("Power-On Password", hardware.power_on_password),
+                ("Keyboard Password", hardware.keyboard_password),
+                ("Administrator Password", hardware.administrator_password),
+            ] {
+                // A password that could be set but isn't.
+                rows.push(row(label, status.to_string(), status == Status::Disabled));
+            }
+            // An enabled front-panel reset lets anyone at the machine restart it.
+            rows.push(row(
+                "Front Panel Reset",
+                hardware.front_panel_reset.to_string(),
+                hardware.front_panel_reset == Status::Enabled,
+            ));
+            rows.push(Row::new(vec![""]));
+        }
+
+        if let Some(reset) = &self.system_reset {
+            rows.push(heading("System Reset"));
+            rows.push(row(
+                "Watchdog Timer",
+                if reset.watchdog_timer {
+                    "Present".to_string()
+                } else {
+                    "Not present".to_string()
+                },
+                false,
+            ));
+            rows.push(row(
+                "Automatic Reset",
+                if reset.enabled {
+                    "Enabled".to_string()
+                } else {
+                    "Disabled".to_string()
+                },
+                false,
+            ));
+            rows.push(row("Boot Option", reset.boot_option.to_string(), false));
+            rows.push(row(
+                "Boot Option on Limit",
+                reset.boot_option_on_limit.to_string(),
+                false,
+            ));
+            rows.push(row("Reset Count", or_unknown(reset.reset_count, ""), false));
+            rows.push(row("Reset Limit", or_unknown(reset.reset_limit, ""), false));
+            rows.push(row(
+                "Timer Interval",
+                or_unknown(reset.timer_interval, " min"),
+                false,
+            ));
+            rows.push(row("Timeout", or_unknown(reset.timeout, " min"), false));
+            rows.push(Row::new(vec![""]));
+        }
+
+        if let Some(status) = self.chassis_security_status {
+            rows.push(heading("Chassis"));
+            rows.push(row(
+                "Security Status",
+                status.to_string(),
+                status.is_insecure(),
+            ));
+        }
+
+        let widths = [Constraint::Length(26), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(Block::new().padding(Padding::uniform(2)));
+        frame.render_widget(table, block.inner(Margin::new(2, 0)));
+    }
+}
+
+fn or_unknown(value: Option<u16>, unit: &str) -> String {
+    match value {
+        Some(v) => format!("{v}{unit}"),
+        None => "Unknown".to_string(),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HardwareSecurity {
+    power_on_password: Status,
+    keyboard_password: Status,
+    administrator_password: Status,
+    front_panel_reset: Status,
+}
+
+impl From<&[u8]> for HardwareSecurity {
+    fn from(data: &[u8]) -> Self {
+        let settings = data[0];
+        Self {
+            power_on_password: Status::from(settings >> 6),
+            keyboard_password: Status::from(settings >> 4),
+            administrator_password: Status::from(settings >> 2),
+            front_panel_reset: Status::from(settings),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+enum Status {
+    #[strum(to_string = "Disabled")]
+    Disabled,
+    #[strum(to_string = "Enabled")]
+    Enabled,
+    #[strum(to_string = "Not Implemented")]
+    NotImplemented,
+    #[strum(to_string = "Unknown")]
+    Unknown,
+}
+
+// Each status is two bits wide.
+impl From<u8> for Status {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0 => Self::Disabled,
+            1 => Self::Enabled,
+            2 => Self::NotImplemented,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SystemReset {
+    // Whether the user enabled the automatic reset on a watchdog timeout.
+    enabled: bool,
+    boot_option: BootOption,
+    boot_option_on_limit: BootOption,
+    watchdog_timer: bool,
+    // Null when unknown. Interval and timeout are in minutes.
+    reset_count: Option<u16>,
+    reset_limit: Option<u16>,
+    timer_interval: Option<u16>,
+    timeout: Option<u16>,
+}
+
+impl From<&[u8]> for SystemReset {
+    fn from(data: &[u8]) -> Self {
+        let capabilities = data[0];
+        // 0xFFFF means unknown.
+        let word = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+                .filter(|v| *v != 0xFFFF)
+        };
+        Self {
+            enabled: capabilities & 1 != 0,
+            boot_option: BootOption::from(capabilities >> 1),
+            boot_option_on_limit: BootOption::from(capabilities >> 3),
+            watchdog_timer: capabilities & (1 << 5) != 0,
+            reset_count: word(1),
+            reset_limit: word(3),
+            timer_interval: word(5),
+            timeout: word(7),
+        }
+    }
+}
+
+// What the system does after a reset.
+#[derive(Debug, strum::Display)]
+enum BootOption {
+    #[strum(to_string = "Reserved")]
+    Reserved,
+    #[strum(to_string = "Operating system")]
+    OperatingSystem,
+    #[strum(to_string = "System utilities")]
+    SystemUtilities,
+    #[strum(to_string = "Do not reboot")]
+    DoNotReboot,
+}
+
+impl From<u8> for BootOption {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0 => Self::Reserved,
+            1 => Self::OperatingSystem,
+            2 => Self::SystemUtilities,
+            _ => Self::DoNotReboot,
+        }
+    }
+}
+
+serialize_display!(Status, BootOption);
@@ -7,6 +7,9 @@ use ratatui::{
     style::{Style, Stylize},
     widgets::{Block, BorderType, Borders, Cell, List, ListItem, ListState, Padding, Row, Table},
 };
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+
+use crate::dmi::serialize_display;
 
 fn string_ref(idx: u8, text: &[String]) -> String {
     if idx == 0 {
@@ -36,6 +39,13 @@ impl Slots {
         self.list.len() >= 2
     }
 
+    /// Selects the element at `index`, in table order.
+    pub fn select(&mut self, index: usize) {
+        if index < self.list.len() {
+            self.selected = index;
+        }
+    }
+
     pub fn handle_key_events(&mut self, key_event: KeyEvent) {
         if !self.has_multiple() {
             return;
@@ -97,6 +107,12 @@ impl Slots {
     }
 }
 
+impl Serialize for Slots {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.list)
+    }
+}
+
 #[derive(Debug)]
 pub struct Slot {
     designation: String,
@@ -109,7 +125,7 @@ pub struct Slot {
 }
 
 #[derive(Debug)]
-struct BusDeviceFunction {
+pub(crate) struct BusDeviceFunction {
     segment: u16,
     bus: u8,
     device: u8,
@@ -126,6 +142,41 @@ impl std::fmt::Display for BusDeviceFunction {
     }
 }
 
+serialize_display!(BusDeviceFunction);
+
+impl BusDeviceFunction {
+    // Unset values are 0xFFFF/0xFF — None if all unset.
+    pub(crate) fn new(segment: u16, bus: u8, devfunc: u8) -> Option<Self> {
+        if segment == 0xFFFF && bus == 0xFF && devfunc == 0xFF {
+            None
+        } else {
+            Some(Self {
+                segment,
+                bus,
+                device: devfunc >> 3,
+                function: devfunc & 0x07,
+            })
+        }
+    }
+}
+
+impl Serialize for Slot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Slot", 7)?;
+        state.serialize_field("designation", &self.designation)?;
+        state.serialize_field("slot_type", &slot_type_name(self.slot_type))?;
+        state.serialize_field("bus_width", &slot_bus_width_name(self.bus_width))?;
+        state.serialize_field(
+            "current_usage",
+            &slot_current_usage_name(self.current_usage),
+        )?;
+        state.serialize_field("length", &slot_length_name(self.length))?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("bdf", &self.bdf)?;
+        state.end()
+    }
+}
+
 impl From<(Vec<u8>, Vec<String>)> for Slot {
     fn from((data, text): (Vec<u8>, Vec<String>)) -> Self {
         let id = u16::from_le_bytes(data[5..7].try_into().unwrap());
@@ -133,19 +184,7 @@ impl From<(Vec<u8>, Vec<String>)> for Slot {
         // Segment/Bus/Device-Function only present in SMBIOS 2.6+
         let bdf = if data.len() >= 13 {
             let segment = u16::from_le_bytes(data[9..11].try_into().unwrap());
-            let bus = data[11];
-            let devfunc = data[12];
-            // Unset values are 0xFFFF/0xFF — skip if all unset.
-            if segment == 0xFFFF && bus == 0xFF && devfunc == 0xFF {
-                None
-            } else {
-                Some(BusDeviceFunction {
-                    segment,
-                    bus,
-                    device: devfunc >> 3,
-                    function: devfunc & 0x07,
-                })
-            }
+            BusDeviceFunction::new(segment, data[11], data[12])
         } else {
             None
         };
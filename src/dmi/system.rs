@@ -6,9 +6,12 @@ use ratatui::{
     style::Stylize,
     widgets::{Block, Cell, Padding, Row, Table},
 };
+use serde::Serialize;
 use uuid::Uuid;
 
-#[derive(Debug)]
+use crate::dmi::{mask, serialize_display};
+
+#[derive(Debug, Serialize)]
 pub struct System {
     manufacturer: String,
     product_name: String,
@@ -17,6 +20,7 @@ pub struct System {
     uuid: String,
     wakeup_type: WakeupType,
     sku: String,
+    #[serde(rename = "family")]
     familly: String,
 }
 
@@ -49,6 +53,8 @@ impl Display for WakeupType {
     }
 }
 
+serialize_display!(WakeupType);
+
 impl From<u8> for WakeupType {
     fn from(value: u8) -> Self {
         match value {
@@ -82,7 +88,7 @@ impl From<(Vec<u8>, Vec<String>)> for System {
 }
 
 impl System {
-    pub fn render(&self, frame: &mut Frame, block: Rect) {
+    pub fn render(&self, frame: &mut Frame, block: Rect, redact: bool) {
         let rows = vec![
             Row::new(vec![
                 Cell::from("Manufacturer").bold(),
@@ -98,11 +104,11 @@ impl System {
             ]),
             Row::new(vec![
                 Cell::from("Serial Number").bold(),
-                Cell::from(self.serial_number.to_string()),
+                Cell::from(mask(&self.serial_number.to_string(), redact)),
             ]),
             Row::new(vec![
                 Cell::from("Uuid").bold(),
-                Cell::from(self.uuid.to_string()),
+                Cell::from(mask(&self.uuid.to_string(), redact)),
             ]),
             Row::new(vec![
                 Cell::from("Wakeup Type").bold(),
@@ -0,0 +1,168 @@
+// Raw access to the SMBIOS structure table, independent of decoding.
+
+use std::{fs, path::Path};
+
+use anyhow::{Result, anyhow, bail};
+
+use crate::dmi::{Header, StructureType};
+
+pub const DMI_TABLE_PATH: &str = "/sys/firmware/dmi/tables/DMI";
+
+pub fn read() -> Result<Vec<u8>> {
+    let path = Path::new(DMI_TABLE_PATH);
+
+    match path.try_exists() {
+        Ok(true) => {}
+        Ok(false) | Err(_) => bail!("No SMBIOS found"),
+    }
+
+    Ok(fs::read(path)?)
+}
+
+// Read a table saved to a file: either the raw table, as copied from
+// DMI_TABLE_PATH, or a `dmidecode --dump-bin` image where the entry point
+// comes first and gives the offset and length of the table.
+pub fn read_file(path: &Path) -> Result<Vec<u8>> {
+    let data = fs::read(path).map_err(|e| anyhow!("{}: {e}", path.display()))?;
+
+    let u16_at = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]) as usize;
+    let u32_at =
+        |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+
+    let (offset, length) = if data.starts_with(b"_SM3_") && data.len() >= 0x18 {
+        (
+            u64::from_le_bytes(data[0x10..0x18].try_into().unwrap()) as usize,
+            u32_at(0x0C),
+        )
+    } else if data.starts_with(b"_SM_") && data.len() >= 0x1C {
+        (u32_at(0x18), u16_at(0x16))
+    } else if data.starts_with(b"_DMI_") && data.len() >= 0x0C {
+        (u32_at(0x08), u16_at(0x06))
+    } else {
+        return Ok(data);
+    };
+
+    if offset >= data.len() {
+        bail!(
+            "{}: table offset {offset:#x} is past the end of the file",
+            path.display()
+        );
+    }
+    let end = (offset + length).min(data.len());
+    Ok(data[offset..end].to_vec())
+}
+
+#[derive(Debug)]
+pub struct Structure {
+    pub header: Header,
+    // Header, formatted area and string-set, exactly as found in the table.
+    pub bytes: Vec<u8>,
+    pub text: Vec<String>,
+}
+
+impl Structure {
+    pub fn type_id(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    // Formatted area without the 4 byte header.
+    pub fn data(&self) -> &[u8] {
+        &self.bytes[4..self.header.length as usize]
+    }
+
+    // String-set, including the terminating double NUL.
+    pub fn strings(&self) -> &[u8] {
+        &self.bytes[self.header.length as usize..]
+    }
+
+    // A structure too short for the fields its decoder always reads, as found
+    // in a truncated or corrupt dump.
+    pub fn check_length(&self) -> Result<()> {
+        let minimum = min_length(&self.header.structure_type);
+        if (self.header.length as usize) < minimum {
+            bail!(
+                "Structure type {} at handle {:#06x} is {} bytes long, expected at least {minimum}",
+                self.type_id(),
+                self.header.handle,
+                self.header.length
+            );
+        }
+        Ok(())
+    }
+}
+
+// Smallest formatted area, header included, that each decoder reads.
+fn min_length(structure_type: &StructureType) -> usize {
+    match structure_type {
+        StructureType::Firmware => 0x18,
+        StructureType::System => 0x1B,
+        StructureType::Baseboard => 0x0E,
+        StructureType::Chassis => 0x15,
+        StructureType::Processor => 0x1A,
+        StructureType::Cache => 0x0B,
+        StructureType::PortConnector => 0x09,
+        StructureType::SystemSlots => 0x0B,
+        StructureType::FirmwareLanguage => 0x16,
+        StructureType::GroupAssociations => 0x05,
+        StructureType::SystemEventLog => 0x14,
+        StructureType::PhysicalMemoryArray => 0x0F,
+        StructureType::MemoryDevice => 0x17,
+        StructureType::MemoryError32 | StructureType::MemoryError64 => 0x07,
+        StructureType::MemoryArrayMappedAddress => 0x0F,
+        StructureType::MemoryDeviceMappedAddress => 0x13,
+        StructureType::PointingDevice => 0x07,
+        StructureType::Battery => 0x1A,
+        StructureType::SystemReset | StructureType::HardwareSecurity => 0x05,
+        StructureType::OnboardDevicesExtended => 0x07,
+        _ => 4,
+    }
+}
+
+// Split a table into its structures, up to and including the End-of-Table
+// structure (type 127) if there is one.
+pub fn parse(table: &[u8]) -> Result<Vec<Structure>> {
+    let mut structures = Vec::new();
+    let mut offset = 0;
+
+    while offset + 4 <= table.len() {
+        let header = Header::from(<[u8; 4]>::try_from(&table[offset..offset + 4]).unwrap());
+
+        if header.length < 4 {
+            bail!("Header size < 4");
+        }
+
+        let formatted_end = offset + header.length as usize;
+        if formatted_end > table.len() {
+            bail!("Truncated structure at handle {:#06x}", header.handle);
+        }
+
+        // The string-set ends with an extra NUL after the last string's
+        // terminator, so for a structure with no strings the formatted area
+        // is followed by two NUL bytes.
+        let strings_length = table[formatted_end..]
+            .windows(2)
+            .position(|w| w == [0, 0])
+            .ok_or_else(|| anyhow!("Unterminated strings at handle {:#06x}", header.handle))?;
+        let end = formatted_end + strings_length + 2;
+
+        let text = table[formatted_end..formatted_end + strings_length]
+            .split(|b| *b == 0)
+            .filter(|s| !s.is_empty())
+            .map(|s| String::from_utf8_lossy(s).to_string())
+            .collect();
+
+        let is_end = header.structure_type == StructureType::End;
+        structures.push(Structure {
+            header,
+            bytes: table[offset..end].to_vec(),
+            text,
+        });
+
+        if is_end {
+            break;
+        }
+        offset = end;
+    }
+
+    Ok(structures)
+}
@@ -0,0 +1,58 @@
+// Non-interactive outputs built from the SMBIOS table.
+
+pub mod cyclonedx;
+pub mod facts;
+pub mod hexdump;
+pub mod lshw;
+pub mod prometheus;
+pub mod redact;
+pub mod redfish;
+pub mod report;
+pub mod sqlite;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+use crate::query::to_text;
+
+// Elements of a list in the model; absent sections are empty.
+pub(crate) fn list(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+// The text of a value, unless it is missing or a placeholder string.
+pub(crate) fn known(value: &Value) -> Option<String> {
+    let text = to_text(value);
+    match text.trim() {
+        "" | "Not Specified" | "Unknown" | "None" => None,
+        _ => Some(text),
+    }
+}
+
+// Current time as an RFC 3339 UTC timestamp.
+pub(crate) fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    // Days since the epoch to a civil date (Howard Hinnant's algorithm).
+    let days = (secs / 86400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let time = secs % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
@@ -0,0 +1,236 @@
+// CycloneDX 1.6 hardware bill of materials.
+//
+// Components are nested the way they are physically contained: the chassis
+// holds the baseboard, which holds the firmware, processors, populated memory
+// devices and slots. Serial numbers, part numbers and other identifiers that
+// have no dedicated CycloneDX field are carried as `dmi:*` properties.
+
+use serde_json::{Map, Value, json};
+
+use super::{known, list, timestamp};
+use crate::query::to_text;
+
+pub fn render(model: &Value) -> String {
+    let system = &model["system"];
+
+    let mut components = Vec::new();
+
+    let firmware = &model["firmware"];
+    if !firmware.is_null() {
+        components.push(component(
+            "firmware",
+            "firmware".to_string(),
+            "System Firmware",
+            firmware,
+            Some("vendor"),
+            Some("firmware_version"),
+            &[
+                ("release_date", "firmware_release_date"),
+                ("release", "platform_firmware_release"),
+                (
+                    "embedded_controller_release",
+                    "embedded_controller_firmware_release",
+                ),
+                ("rom_size", "firmware_rom_size"),
+            ],
+        ));
+    }
+
+    for (i, processor) in list(&model["processors"]).iter().enumerate() {
+        let name = known(&processor["version"])
+            .or_else(|| known(&processor["family"]))
+            .unwrap_or_else(|| "Processor".to_string());
+        let mut c = component(
+            "device",
+            format!("processor-{i}"),
+            &name,
+            processor,
+            Some("manufacturer"),
+            None,
+            &[
+                ("socket", "socket_designation"),
+                ("serial_number", "serial_number"),
+                ("part_number", "part_number"),
+                ("asset_tag", "asset_tag"),
+                ("core_count", "core_count"),
+                ("thread_count", "thread_count"),
+                ("max_speed_mhz", "max_speed"),
+            ],
+        );
+        c["description"] = json!(format!(
+            "Processor in {}",
+            to_text(&processor["socket_designation"])
+        ));
+        components.push(c);
+    }
+
+    for (i, device) in list(&model["memory"]["devices"]).iter().enumerate() {
+        // Empty sockets are not part of the bill of materials.
+        if device["size_mb"] == 0 {
+            continue;
+        }
+        let name = known(&device["part_number"]).unwrap_or_else(|| "Memory Device".to_string());
+        let mut c = component(
+            "device",
+            format!("memory-device-{i}"),
+            &name,
+            device,
+            Some("manufacturer"),
+            None,
+            &[
+                ("locator", "device_locator"),
+                ("bank_locator", "bank_locator"),
+                ("serial_number", "serial_number"),
+                ("part_number", "part_number"),
+                ("asset_tag", "asset_tag"),
+                ("size", "size"),
+                ("type", "memory_type"),
+                ("form_factor", "form_factor"),
+                ("speed_mts", "speed"),
+            ],
+        );
+        c["description"] = json!(format!(
+            "{} {} in {}",
+            to_text(&device["size"]),
+            to_text(&device["memory_type"]),
+            to_text(&device["device_locator"])
+        ));
+        components.push(c);
+    }
+
+    for (i, slot) in list(&model["slots"]).iter().enumerate() {
+        let mut c = component(
+            "device",
+            format!("slot-{i}"),
+            &to_text(&slot["designation"]),
+            slot,
+            None,
+            None,
+            &[
+                ("usage", "current_usage"),
+                ("bus_width", "bus_width"),
+                ("length", "length"),
+                ("bus_address", "bdf"),
+            ],
+        );
+        c["description"] = slot["slot_type"].clone();
+        components.push(c);
+    }
+
+    let baseboard = &model["baseboard"];
+    if !baseboard.is_null() {
+        let mut c = component(
+            "device",
+            "baseboard".to_string(),
+            &known(&baseboard["product"]).unwrap_or_else(|| "Baseboard".to_string()),
+            baseboard,
+            Some("manufacturer"),
+            Some("version"),
+            &[
+                ("serial_number", "serial_number"),
+                ("asset_tag", "asset_tag"),
+                ("board_type", "board_type"),
+                ("location_in_chassis", "location_in_chassis"),
+            ],
+        );
+        nest(&mut c, components);
+        components = vec![c];
+    }
+
+    let chassis = &model["chassis"];
+    if !chassis.is_null() {
+        let mut c = component(
+            "device",
+            "chassis".to_string(),
+            &to_text(&chassis["chassis_type"]),
+            chassis,
+            Some("manufacturer"),
+            Some("version"),
+            &[
+                ("serial_number", "serial_number"),
+                ("asset_tag", "asset_tag_number"),
+                ("sku", "sku_number"),
+                ("height_u", "height"),
+                ("power_cords", "number_power_cords"),
+            ],
+        );
+        nest(&mut c, components);
+        components = vec![c];
+    }
+
+    let mut metadata = json!({
+        "timestamp": timestamp(),
+        "tools": {
+            "components": [{
+                "type": "application",
+                "name": "dmitui",
+                "version": env!("CARGO_PKG_VERSION"),
+            }],
+        },
+    });
+    if !system.is_null() {
+        metadata["component"] = component(
+            "device",
+            "system".to_string(),
+            &known(&system["product_name"]).unwrap_or_else(|| "System".to_string()),
+            system,
+            Some("manufacturer"),
+            Some("version"),
+            &[
+                ("serial_number", "serial_number"),
+                ("uuid", "uuid"),
+                ("sku", "sku"),
+                ("family", "family"),
+            ],
+        );
+    }
+
+    let bom = json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.6",
+        "version": 1,
+        "metadata": metadata,
+        "components": components,
+    });
+
+    serde_json::to_string_pretty(&bom).expect("BOM is always serializable")
+}
+
+fn component(
+    kind: &str,
+    bom_ref: String,
+    name: &str,
+    value: &Value,
+    manufacturer: Option<&str>,
+    version: Option<&str>,
+    properties: &[(&str, &str)],
+) -> Value {
+    let mut c = Map::new();
+    c.insert("type".into(), json!(kind));
+    c.insert("bom-ref".into(), json!(bom_ref));
+    c.insert("name".into(), json!(name));
+    if let Some(manufacturer) = manufacturer.and_then(|key| known(&value[key])) {
+        c.insert("manufacturer".into(), json!({ "name": manufacturer }));
+    }
+    if let Some(version) = version.and_then(|key| known(&value[key])) {
+        c.insert("version".into(), json!(version));
+    }
+
+    let properties: Vec<Value> = properties
+        .iter()
+        .filter_map(|(name, key)| {
+            known(&value[*key]).map(|v| json!({ "name": format!("dmi:{name}"), "value": v }))
+        })
+        .collect();
+    if !properties.is_empty() {
+        c.insert("properties".into(), Value::Array(properties));
+    }
+
+    Value::Object(c)
+}
+
+fn nest(parent: &mut Value, children: Vec<Value>) {
+    if !children.is_empty() {
+        parent["components"] = Value::Array(children);
+    }
+}
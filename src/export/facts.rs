@@ -0,0 +1,220 @@
+// Custom facts for configuration management, as JSON.
+//
+// The document holds a nested `dmi` fact and the same data flattened into
+// `dmi_*` facts, which suits both Ansible's facts.d and Facter external facts.
+// Fact names come from the tables below rather than from the model, so they
+// stay the same when the decoding changes.
+
+use serde_json::{Map, Value};
+
+use super::list;
+
+const FIRMWARE: &[(&str, &str)] = &[
+    ("vendor", "vendor"),
+    ("version", "firmware_version"),
+    ("release_date", "firmware_release_date"),
+    ("release", "platform_firmware_release"),
+    ("rom_size", "firmware_rom_size"),
+    ("uefi", "firmware_characteristics_extensions/uefi_spec"),
+    (
+        "virtual_machine",
+        "firmware_characteristics_extensions/virtual_machine",
+    ),
+];
+
+const SYSTEM: &[(&str, &str)] = &[
+    ("manufacturer", "manufacturer"),
+    ("product_name", "product_name"),
+    ("version", "version"),
+    ("serial_number", "serial_number"),
+    ("uuid", "uuid"),
+    ("sku", "sku"),
+    ("family", "family"),
+    ("wakeup_type", "wakeup_type"),
+];
+
+const BASEBOARD: &[(&str, &str)] = &[
+    ("manufacturer", "manufacturer"),
+    ("product", "product"),
+    ("version", "version"),
+    ("serial_number", "serial_number"),
+    ("asset_tag", "asset_tag"),
+    ("type", "board_type"),
+];
+
+const CHASSIS: &[(&str, &str)] = &[
+    ("manufacturer", "manufacturer"),
+    ("type", "chassis_type"),
+    ("version", "version"),
+    ("serial_number", "serial_number"),
+    ("asset_tag", "asset_tag_number"),
+    ("sku", "sku_number"),
+    ("height_u", "height"),
+    ("power_cords", "number_power_cords"),
+];
+
+const PROCESSOR: &[(&str, &str)] = &[
+    ("socket", "socket_designation"),
+    ("manufacturer", "manufacturer"),
+    ("version", "version"),
+    ("family", "family"),
+    ("status", "status"),
+    ("cores", "core_count"),
+    ("cores_enabled", "core_enabled"),
+    ("threads", "thread_count"),
+    ("max_speed_mhz", "max_speed"),
+    ("current_speed_mhz", "current_speed"),
+    ("serial_number", "serial_number"),
+    ("part_number", "part_number"),
+    ("l1_cache_kb", "l1_cache/installed_size_kb"),
+    ("l2_cache_kb", "l2_cache/installed_size_kb"),
+    ("l3_cache_kb", "l3_cache/installed_size_kb"),
+];
+
+const MEMORY_ARRAY: &[(&str, &str)] = &[
+    ("location", "location"),
+    ("error_correction", "error_correction"),
+    ("max_capacity", "max_capacity"),
+    ("slots", "number_memory_devices"),
+];
+
+const MEMORY_DEVICE: &[(&str, &str)] = &[
+    ("locator", "device_locator"),
+    ("bank_locator", "bank_locator"),
+    ("size_mb", "size_mb"),
+    ("type", "memory_type"),
+    ("form_factor", "form_factor"),
+    ("speed_mts", "speed"),
+    ("configured_speed_mts", "configured_speed"),
+    ("rank", "rank"),
+    ("manufacturer", "manufacturer"),
+    ("serial_number", "serial_number"),
+    ("part_number", "part_number"),
+];
+
+const SLOT: &[(&str, &str)] = &[
+    ("designation", "designation"),
+    ("type", "slot_type"),
+    ("usage", "current_usage"),
+    ("bus_width", "bus_width"),
+    ("length", "length"),
+    ("bus_address", "bdf"),
+];
+
+const BATTERY: &[(&str, &str)] = &[
+    ("location", "location"),
+    ("manufacturer", "manufacturer"),
+    ("name", "device_name"),
+    ("chemistry", "device_chemistry"),
+    ("serial_number", "serial_number"),
+    ("manufacture_date", "manufacture_date"),
+    ("design_capacity_mwh", "design_capacity"),
+    ("design_voltage_mv", "design_voltage"),
+];
+
+pub fn render(model: &Value) -> String {
+    let mut dmi = Map::new();
+
+    for (key, fields) in [
+        ("firmware", FIRMWARE),
+        ("system", SYSTEM),
+        ("baseboard", BASEBOARD),
+        ("chassis", CHASSIS),
+    ] {
+        if !model[key].is_null() {
+            dmi.insert(key.into(), pick(&model[key], fields));
+        }
+    }
+
+    let processors = list(&model["processors"]);
+    if !processors.is_empty() {
+        let total = |key: &str| -> u64 { processors.iter().filter_map(|p| p[key].as_u64()).sum() };
+        let mut summary = Map::new();
+        summary.insert("count".into(), processors.len().into());
+        summary.insert("cores".into(), total("core_count").into());
+        summary.insert("threads".into(), total("thread_count").into());
+        dmi.insert("processor".into(), Value::Object(summary));
+        dmi.insert(
+            "processors".into(),
+            processors.iter().map(|p| pick(p, PROCESSOR)).collect(),
+        );
+    }
+
+    let memory = &model["memory"];
+    if !memory.is_null() {
+        let devices = list(&memory["devices"]);
+        let mut facts = match pick(&memory["array"], MEMORY_ARRAY) {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        let total_mb: u64 = devices.iter().filter_map(|d| d["size_mb"].as_u64()).sum();
+        let populated = devices
+            .iter()
+            .filter(|d| d["size_mb"].as_u64().is_some_and(|mb| mb > 0))
+            .count();
+        facts.insert("total_mb".into(), total_mb.into());
+        facts.insert("populated".into(), populated.into());
+        facts.insert(
+            "devices".into(),
+            devices.iter().map(|d| pick(d, MEMORY_DEVICE)).collect(),
+        );
+        dmi.insert("memory".into(), Value::Object(facts));
+    }
+
+    let slots = list(&model["slots"]);
+    if !slots.is_empty() {
+        dmi.insert(
+            "slots".into(),
+            slots.iter().map(|s| pick(s, SLOT)).collect(),
+        );
+    }
+
+    let batteries = list(&model["batteries"]);
+    if !batteries.is_empty() {
+        dmi.insert(
+            "batteries".into(),
+            batteries.iter().map(|b| pick(b, BATTERY)).collect(),
+        );
+    }
+
+    let dmi = Value::Object(dmi);
+    let mut facts = Map::new();
+    facts.insert("dmi".into(), dmi.clone());
+    flatten(&mut facts, "dmi", &dmi);
+
+    serde_json::to_string_pretty(&facts).expect("facts are always serializable")
+}
+
+// The listed fields of `value` under their fact names, skipping absent ones.
+fn pick(value: &Value, fields: &[(&str, &str)]) -> Value {
+    fields
+        .iter()
+        .filter_map(|(name, key)| {
+            value
+                .pointer(&format!("/{key}"))
+                .filter(|v| !v.is_null())
+                .map(|v| (name.to_string(), v.clone()))
+        })
+        .collect::<Map<String, Value>>()
+        .into()
+}
+
+// `{"memory": {"devices": [{"size_mb": 1}]}}` becomes `dmi_memory_devices_0_size_mb`.
+fn flatten(facts: &mut Map<String, Value>, prefix: &str, value: &Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                flatten(facts, &format!("{prefix}_{key}"), v);
+            }
+        }
+        Value::Array(list) => {
+            facts.insert(format!("{prefix}_count"), list.len().into());
+            for (i, v) in list.iter().enumerate() {
+                flatten(facts, &format!("{prefix}_{i}"), v);
+            }
+        }
+        v => {
+            facts.insert(prefix.to_string(), v.clone());
+        }
+    }
+}
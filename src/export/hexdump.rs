@@ -0,0 +1,56 @@
+// Undecoded dump of every structure, laid out like `dmidecode --dump` so the
+// text can be pasted into tickets and read back by dmidecode-style parsers.
+
+use std::fmt::Write;
+
+use crate::dmi::table::Structure;
+
+pub fn render(structures: &[Structure]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# dmitui {}", env!("CARGO_PKG_VERSION")).unwrap();
+
+    for structure in structures {
+        let length = structure.header.length as usize;
+
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "Handle 0x{:04X}, DMI type {}, {} bytes",
+            structure.header.handle,
+            structure.type_id(),
+            length
+        )
+        .unwrap();
+
+        writeln!(out, "\tHeader and Data:").unwrap();
+        hex_rows(&mut out, &structure.bytes[..length]);
+
+        let strings = structure.strings();
+        if strings.starts_with(&[0, 0]) {
+            continue;
+        }
+
+        writeln!(out, "\tStrings:").unwrap();
+        for string in strings.split(|b| *b == 0).filter(|s| !s.is_empty()) {
+            // The hex rows include the NUL terminator, as dmidecode does.
+            let mut raw = string.to_vec();
+            raw.push(0);
+            hex_rows(&mut out, &raw);
+
+            let printable: String = String::from_utf8_lossy(string)
+                .chars()
+                .map(|c| if c.is_control() { '.' } else { c })
+                .collect();
+            writeln!(out, "\t\t\"{printable}\"").unwrap();
+        }
+    }
+
+    out
+}
+
+fn hex_rows(out: &mut String, bytes: &[u8]) {
+    for row in bytes.chunks(16) {
+        let hex: Vec<String> = row.iter().map(|b| format!("{b:02X}")).collect();
+        writeln!(out, "\t\t{}", hex.join(" ")).unwrap();
+    }
+}
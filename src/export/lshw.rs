@@ -0,0 +1,665 @@
+// The SMBIOS-derived part of the `lshw` device tree, in the node format of
+// `lshw -json` and `lshw -xml`: the system, its motherboard (`core`) with the
+// firmware, processors and their caches, the memory banks and the slots.
+
+use std::fmt::Write;
+
+use serde_json::{Map, Value, json};
+
+use super::{known, list};
+use crate::query::to_text;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Xml,
+}
+
+impl From<&str> for Format {
+    fn from(value: &str) -> Self {
+        match value {
+            "xml" => Self::Xml,
+            _ => Self::Json,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    id: String,
+    class: &'static str,
+    claimed: bool,
+    handle: Option<String>,
+    description: Option<String>,
+    product: Option<String>,
+    vendor: Option<String>,
+    physid: Option<String>,
+    businfo: Option<String>,
+    version: Option<String>,
+    date: Option<String>,
+    serial: Option<String>,
+    slot: Option<String>,
+    units: Option<&'static str>,
+    size: Option<u64>,
+    capacity: Option<u64>,
+    clock: Option<u64>,
+    configuration: Vec<(&'static str, String)>,
+    capabilities: Vec<(&'static str, &'static str)>,
+    children: Vec<Node>,
+}
+
+// Firmware characteristics and the lshw capabilities they map to.
+const FIRMWARE_CAPABILITIES: &[(&str, &str, &str)] = &[
+    ("firmware_characteristics/isa", "isa", "ISA bus"),
+    ("firmware_characteristics/pci", "pci", "PCI bus"),
+    ("firmware_characteristics/pcmcia", "pcmcia", "PCMCIA/PCCard"),
+    (
+        "firmware_characteristics/plug_and_play",
+        "pnp",
+        "Plug-and-Play",
+    ),
+    (
+        "firmware_characteristics/apm",
+        "apm",
+        "Advanced Power Management",
+    ),
+    (
+        "firmware_characteristics/firmware_is_upgradeable",
+        "upgrade",
+        "BIOS EEPROM can be upgraded",
+    ),
+    (
+        "firmware_characteristics/firmware_shadowing",
+        "shadowing",
+        "BIOS shadowing",
+    ),
+    ("firmware_characteristics/escd", "escd", "ESCD"),
+    (
+        "firmware_characteristics/boot_from_cd",
+        "cdboot",
+        "Booting from CD-ROM/DVD",
+    ),
+    (
+        "firmware_characteristics/selectable_boot",
+        "bootselect",
+        "Selectable boot path",
+    ),
+    (
+        "firmware_characteristics/firmware_rom_is_socketed",
+        "socketedrom",
+        "BIOS ROM is socketed",
+    ),
+    (
+        "firmware_characteristics/boot_from_pcmcia",
+        "pcmciaboot",
+        "Booting from PCMCIA",
+    ),
+    (
+        "firmware_characteristics/edd_specification",
+        "edd",
+        "Enhanced Disk Drive extensions",
+    ),
+    (
+        "firmware_characteristics/int_13h_japanese_floppy_for_nec",
+        "int13floppynec",
+        "NEC 9800 floppy",
+    ),
+    (
+        "firmware_characteristics/int_13h_japanese_floppy_for_toshiba",
+        "int13floppytoshiba",
+        "Toshiba floppy",
+    ),
+    (
+        "firmware_characteristics/int_13h_360",
+        "int13floppy360",
+        "5.25\" 360KB floppy",
+    ),
+    (
+        "firmware_characteristics/int_13h_1_2",
+        "int13floppy1200",
+        "5.25\" 1.2MB floppy",
+    ),
+    (
+        "firmware_characteristics/int_13h_720",
+        "int13floppy720",
+        "3.5\" 720KB floppy",
+    ),
+    (
+        "firmware_characteristics/int_13h_2_88",
+        "int13floppy2880",
+        "3.5\" 2.88MB floppy",
+    ),
+    (
+        "firmware_characteristics/int_5h_print_screen",
+        "int5printscreen",
+        "Print Screen key",
+    ),
+    (
+        "firmware_characteristics/int_9h_8042",
+        "int9keyboard",
+        "i8042 keyboard controller",
+    ),
+    (
+        "firmware_characteristics/int_14h_serial_service",
+        "int14serial",
+        "INT14 serial line control",
+    ),
+    (
+        "firmware_characteristics/int_17h_printer_service",
+        "int17printer",
+        "INT17 printer control",
+    ),
+    (
+        "firmware_characteristics/int_10h_cga",
+        "int10video",
+        "INT10 CGA/Mono video",
+    ),
+    ("firmware_characteristics/nec_pc_98", "pc98", "NEC PC-98"),
+    ("firmware_characteristics_extensions/acpi", "acpi", "ACPI"),
+    (
+        "firmware_characteristics_extensions/usb_legacy",
+        "usb",
+        "USB legacy emulation",
+    ),
+    ("firmware_characteristics_extensions/agp", "agp", "AGP"),
+    (
+        "firmware_characteristics_extensions/ls_120_superdisk_boot",
+        "ls120boot",
+        "Booting from LS-120",
+    ),
+    (
+        "firmware_characteristics_extensions/atapi_zip_drive_boot",
+        "zipboot",
+        "Booting from ATAPI ZIP",
+    ),
+    (
+        "firmware_characteristics_extensions/ieee_1394_boot",
+        "ieee1394boot",
+        "Booting from IEEE-1394 (Firewire)",
+    ),
+    (
+        "firmware_characteristics_extensions/smart_battery",
+        "smartbattery",
+        "Smart battery",
+    ),
+    (
+        "firmware_characteristics_extensions/bios_boot_spec",
+        "biosbootspecification",
+        "BIOS boot specification",
+    ),
+    (
+        "firmware_characteristics_extensions/function_key_initiated_network_service",
+        "netboot",
+        "Function-key initiated network service boot",
+    ),
+    (
+        "firmware_characteristics_extensions/uefi_spec",
+        "uefi",
+        "UEFI specification is supported",
+    ),
+    (
+        "firmware_characteristics_extensions/virtual_machine",
+        "virtualmachine",
+        "This machine is a virtual machine",
+    ),
+];
+
+pub fn render(model: &Value, format: Format) -> String {
+    let root = system(model);
+    match format {
+        Format::Json => serde_json::to_string_pretty(&json_node(&root))
+            .expect("lshw tree is always serializable"),
+        Format::Xml => {
+            let mut out = String::new();
+            writeln!(out, "<?xml version=\"1.0\" standalone=\"yes\" ?>").unwrap();
+            writeln!(
+                out,
+                "<!-- generated by dmitui {} -->",
+                env!("CARGO_PKG_VERSION")
+            )
+            .unwrap();
+            xml_node(&mut out, &root, 0);
+            out
+        }
+    }
+}
+
+fn system(model: &Value) -> Node {
+    let s = &model["system"];
+    let chassis_type = known(&model["chassis"]["chassis_type"]);
+
+    let mut node = Node {
+        id: "computer".to_string(),
+        class: "system",
+        claimed: true,
+        description: chassis_type
+            .clone()
+            .or_else(|| Some("Computer".to_string())),
+        product: known(&s["product_name"]).map(|product| match known(&s["sku"]) {
+            Some(sku) => format!("{product} ({sku})"),
+            None => product,
+        }),
+        vendor: known(&s["manufacturer"]),
+        version: known(&s["version"]),
+        serial: known(&s["serial_number"]),
+        ..Default::default()
+    };
+    if let Some(chassis) = chassis_type {
+        let short = chassis
+            .to_lowercase()
+            .replace("chassis", "")
+            .replace([' ', '-'], "");
+        node.configuration.push(("chassis", short));
+    }
+    for (setting, key) in [("family", "family"), ("sku", "sku"), ("uuid", "uuid")] {
+        if let Some(value) = known(&s[key]) {
+            node.configuration.push((setting, value));
+        }
+    }
+
+    if model["baseboard"].is_null() {
+        node.children = components(model);
+    } else {
+        node.children.push(core(model));
+    }
+
+    node
+}
+
+fn core(model: &Value) -> Node {
+    let b = &model["baseboard"];
+    Node {
+        id: "core".to_string(),
+        class: "bus",
+        claimed: true,
+        description: Some("Motherboard".to_string()),
+        product: known(&b["product"]),
+        vendor: known(&b["manufacturer"]),
+        physid: Some("0".to_string()),
+        version: known(&b["version"]),
+        serial: known(&b["serial_number"]),
+        slot: known(&b["location_in_chassis"]),
+        children: components(model),
+        ..Default::default()
+    }
+}
+
+// Everything that sits on the motherboard.
+fn components(model: &Value) -> Vec<Node> {
+    let mut nodes = Vec::new();
+
+    if !model["firmware"].is_null() {
+        nodes.push(firmware(&model["firmware"]));
+    }
+
+    let processors = list(&model["processors"]);
+    for (i, p) in processors.iter().enumerate() {
+        nodes.push(processor(p, i, processors.len() > 1));
+    }
+
+    if !model["memory"].is_null() {
+        nodes.push(memory(&model["memory"]));
+    }
+
+    for (i, s) in list(&model["slots"]).iter().enumerate() {
+        nodes.push(slot(s, i));
+    }
+
+    nodes
+}
+
+fn firmware(f: &Value) -> Node {
+    let mut node = Node {
+        id: "firmware".to_string(),
+        class: "memory",
+        claimed: true,
+        description: Some("BIOS".to_string()),
+        vendor: known(&f["vendor"]),
+        physid: Some("0".to_string()),
+        version: known(&f["firmware_version"]),
+        date: known(&f["firmware_release_date"]),
+        units: Some("bytes"),
+        // The runtime size, from the segment the BIOS is loaded at. UEFI
+        // firmware often reports segment 0, which says nothing.
+        size: f["bios_starting_addr_segment"]
+            .as_u64()
+            .filter(|segment| *segment != 0)
+            .map(|segment| (0x10000 - segment) * 16),
+        capacity: f["firmware_rom_size"].as_str().and_then(bytes),
+        ..Default::default()
+    };
+    node.capabilities = FIRMWARE_CAPABILITIES
+        .iter()
+        .filter(|(pointer, _, _)| f.pointer(&format!("/{pointer}")) == Some(&Value::Bool(true)))
+        .map(|(_, id, text)| (*id, *text))
+        .collect();
+    node
+}
+
+fn processor(p: &Value, index: usize, multiple: bool) -> Node {
+    let mut node = Node {
+        id: if multiple {
+            format!("cpu:{index}")
+        } else {
+            "cpu".to_string()
+        },
+        class: "processor",
+        claimed: !to_text(&p["status"]).starts_with("Unpopulated"),
+        description: Some("CPU".to_string()),
+        product: known(&p["version"]),
+        vendor: known(&p["manufacturer"]),
+        physid: Some(format!("{:x}", index + 1)),
+        businfo: Some(format!("cpu@{index}")),
+        version: known(&p["family"]),
+        serial: known(&p["serial_number"]),
+        slot: known(&p["socket_designation"]),
+        units: Some("Hz"),
+        size: p["current_speed"].as_u64().map(|mhz| mhz * 1_000_000),
+        capacity: p["max_speed"].as_u64().map(|mhz| mhz * 1_000_000),
+        ..Default::default()
+    };
+    for (setting, key) in [
+        ("cores", "core_count"),
+        ("enabledcores", "core_enabled"),
+        ("threads", "thread_count"),
+    ] {
+        if let Some(value) = p[key].as_u64() {
+            node.configuration.push((setting, value.to_string()));
+        }
+    }
+
+    let caches = [1, 2, 3]
+        .into_iter()
+        .filter(|level| !p[format!("l{level}_cache")].is_null());
+    for (i, level) in caches.enumerate() {
+        let c = &p[format!("l{level}_cache")];
+        let size = c["installed_size_kb"].as_u64().map(|kb| kb * 1024);
+        let mut cache = Node {
+            id: format!("cache:{i}"),
+            class: "memory",
+            claimed: true,
+            handle: c["handle"].as_u64().map(|h| format!("DMI:{h:04X}")),
+            description: Some(format!("L{level} cache")),
+            physid: c["handle"].as_u64().map(|h| format!("{h:x}")),
+            units: Some("bytes"),
+            size,
+            capacity: size,
+            ..Default::default()
+        };
+        cache.configuration.push(("level", level.to_string()));
+        match c["cache_type"].as_str() {
+            Some("Data") => cache.capabilities.push(("data", "Data cache")),
+            Some("Instruction") => cache
+                .capabilities
+                .push(("instruction", "Instruction cache")),
+            Some("Unified") => cache.capabilities.push(("unified", "Unified cache")),
+            _ => {}
+        }
+        node.children.push(cache);
+    }
+
+    node
+}
+
+fn memory(m: &Value) -> Node {
+    let array = &m["array"];
+    let devices = list(&m["devices"]);
+
+    let mut node = Node {
+        id: "memory".to_string(),
+        class: "memory",
+        claimed: true,
+        description: known(&array["function"]).or_else(|| Some("System Memory".to_string())),
+        physid: Some("1000".to_string()),
+        slot: known(&array["location"]),
+        units: Some("bytes"),
+        size: Some(
+            devices
+                .iter()
+                .filter_map(|d| d["size_mb"].as_u64())
+                .sum::<u64>()
+                * 1024
+                * 1024,
+        ),
+        capacity: array["max_capacity"].as_str().and_then(bytes),
+        ..Default::default()
+    };
+    let (detection, capability) = match array["error_correction"].as_str() {
+        Some("Multi-bit ECC") => (
+            "multi-bit-ecc",
+            Some(("ecc", "Multi-bit error-correcting code (ECC)")),
+        ),
+        Some("Single-bit ECC") => (
+            "ecc",
+            Some(("ecc", "Single-bit error-correcting code (ECC)")),
+        ),
+        Some("Parity") => ("parity", Some(("parity", "Parity error detection"))),
+        Some("CRC") => ("crc", Some(("crc", "CRC error detection"))),
+        Some("None") => ("none", None),
+        _ => ("unknown", None),
+    };
+    node.configuration
+        .push(("errordetection", detection.to_string()));
+    node.capabilities.extend(capability);
+
+    for (i, d) in devices.iter().enumerate() {
+        let size = d["size_mb"].as_u64().filter(|mb| *mb > 0);
+        let description = match size {
+            Some(_) => {
+                let mut text = [&d["form_factor"], &d["memory_type"]]
+                    .into_iter()
+                    .filter_map(known)
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                if let Some(speed) = d["speed"].as_u64().filter(|s| *s > 0) {
+                    write!(text, " {speed} MHz ({:.1} ns)", 1000.0 / speed as f64).unwrap();
+                }
+                text
+            }
+            None => format!("{} [empty]", to_text(&d["form_factor"])),
+        };
+        node.children.push(Node {
+            id: format!("bank:{i}"),
+            class: "memory",
+            claimed: size.is_some(),
+            description: Some(description.trim().to_string()),
+            product: size.and(known(&d["part_number"])),
+            vendor: size.and(known(&d["manufacturer"])),
+            physid: Some(format!("{i:x}")),
+            serial: size.and(known(&d["serial_number"])),
+            slot: known(&d["device_locator"]),
+            units: size.map(|_| "bytes"),
+            size: size.map(|mb| mb * 1024 * 1024),
+            clock: size.and(d["speed"].as_u64()).map(|mhz| mhz * 1_000_000),
+            ..Default::default()
+        });
+    }
+
+    node
+}
+
+fn slot(s: &Value, index: usize) -> Node {
+    let mut node = Node {
+        id: format!("slot:{index}"),
+        class: "bus",
+        claimed: s["current_usage"] == "In Use",
+        description: known(&s["slot_type"]),
+        physid: Some(format!("{:x}", 0x100 + index)),
+        businfo: known(&s["bdf"]).map(|bdf| format!("pci@{bdf}")),
+        slot: known(&s["designation"]),
+        ..Default::default()
+    };
+    for (setting, key) in [
+        ("usage", "current_usage"),
+        ("buswidth", "bus_width"),
+        ("length", "length"),
+    ] {
+        if let Some(value) = known(&s[key]) {
+            node.configuration.push((setting, value));
+        }
+    }
+    node
+}
+
+// "32M", "256G" and the like, as produced by the decoders.
+fn bytes(size: &str) -> Option<u64> {
+    let (value, unit) = size.split_at(size.len().checked_sub(1)?);
+    let shift = match unit {
+        "K" => 10,
+        "M" => 20,
+        "G" => 30,
+        "T" => 40,
+        _ => return None,
+    };
+    value.parse::<u64>().ok().map(|v| v << shift)
+}
+
+fn json_node(node: &Node) -> Value {
+    let mut map = Map::new();
+    map.insert("id".into(), json!(node.id));
+    map.insert("class".into(), json!(node.class));
+    map.insert("claimed".into(), json!(node.claimed));
+
+    let text = [
+        ("handle", &node.handle),
+        ("description", &node.description),
+        ("product", &node.product),
+        ("vendor", &node.vendor),
+        ("physid", &node.physid),
+        ("businfo", &node.businfo),
+        ("version", &node.version),
+        ("date", &node.date),
+        ("serial", &node.serial),
+        ("slot", &node.slot),
+    ];
+    for (key, value) in text {
+        if let Some(value) = value {
+            map.insert(key.into(), json!(value));
+        }
+    }
+    if let Some(units) = node.units {
+        map.insert("units".into(), json!(units));
+    }
+    let numbers = [
+        ("size", node.size),
+        ("capacity", node.capacity),
+        ("clock", node.clock),
+    ];
+    for (key, value) in numbers {
+        if let Some(value) = value {
+            map.insert(key.into(), json!(value));
+        }
+    }
+
+    if !node.configuration.is_empty() {
+        let configuration: Map<String, Value> = node
+            .configuration
+            .iter()
+            .map(|(k, v)| (k.to_string(), json!(v)))
+            .collect();
+        map.insert("configuration".into(), Value::Object(configuration));
+    }
+    if !node.capabilities.is_empty() {
+        let capabilities: Map<String, Value> = node
+            .capabilities
+            .iter()
+            .map(|(k, v)| (k.to_string(), json!(v)))
+            .collect();
+        map.insert("capabilities".into(), Value::Object(capabilities));
+    }
+    if !node.children.is_empty() {
+        map.insert(
+            "children".into(),
+            node.children.iter().map(json_node).collect(),
+        );
+    }
+
+    Value::Object(map)
+}
+
+fn xml_node(out: &mut String, node: &Node, depth: usize) {
+    let indent = "  ".repeat(depth);
+    write!(
+        out,
+        "{indent}<node id=\"{}\" class=\"{}\"",
+        escape(&node.id),
+        node.class
+    )
+    .unwrap();
+    if node.claimed {
+        write!(out, " claimed=\"true\"").unwrap();
+    }
+    if let Some(handle) = &node.handle {
+        write!(out, " handle=\"{}\"", escape(handle)).unwrap();
+    }
+    writeln!(out, ">").unwrap();
+
+    let text = [
+        ("description", &node.description),
+        ("product", &node.product),
+        ("vendor", &node.vendor),
+        ("physid", &node.physid),
+        ("businfo", &node.businfo),
+        ("version", &node.version),
+        ("date", &node.date),
+        ("serial", &node.serial),
+        ("slot", &node.slot),
+    ];
+    for (tag, value) in text {
+        if let Some(value) = value {
+            writeln!(out, "{indent}  <{tag}>{}</{tag}>", escape(value)).unwrap();
+        }
+    }
+    let numbers = [
+        ("size", node.size, node.units),
+        ("capacity", node.capacity, node.units),
+        ("clock", node.clock, Some("Hz")),
+    ];
+    for (tag, value, units) in numbers {
+        if let Some(value) = value {
+            match units {
+                Some(units) => {
+                    writeln!(out, "{indent}  <{tag} units=\"{units}\">{value}</{tag}>").unwrap()
+                }
+                None => writeln!(out, "{indent}  <{tag}>{value}</{tag}>").unwrap(),
+            }
+        }
+    }
+
+    if !node.configuration.is_empty() {
+        writeln!(out, "{indent}  <configuration>").unwrap();
+        for (id, value) in &node.configuration {
+            writeln!(
+                out,
+                "{indent}    <setting id=\"{id}\" value=\"{}\" />",
+                escape(value)
+            )
+            .unwrap();
+        }
+        writeln!(out, "{indent}  </configuration>").unwrap();
+    }
+    if !node.capabilities.is_empty() {
+        writeln!(out, "{indent}  <capabilities>").unwrap();
+        for (id, text) in &node.capabilities {
+            writeln!(
+                out,
+                "{indent}    <capability id=\"{id}\" >{}</capability>",
+                escape(text)
+            )
+            .unwrap();
+        }
+        writeln!(out, "{indent}  </capabilities>").unwrap();
+    }
+
+    for child in &node.children {
+        xml_node(out, child, depth + 1);
+    }
+    writeln!(out, "{indent}</node>").unwrap();
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,399 @@
+// Metrics in the Prometheus text exposition format, for node_exporter's
+// textfile collector. Descriptive strings are exposed as `_info` metrics with
+// a constant value of 1, quantities as gauges in base units.
+
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use super::list;
+use crate::query::to_text;
+
+type Labels = Vec<(&'static str, String)>;
+
+// Chassis states, in SMBIOS order (DSP0134 §7.4.2).
+const CHASSIS_STATES: &[&str] = &[
+    "Other",
+    "Unknown",
+    "Safe",
+    "Warning",
+    "Critical",
+    "Non Recoverable",
+];
+
+pub fn render(model: &Value) -> String {
+    let mut out = String::new();
+
+    let firmware = &model["firmware"];
+    if !firmware.is_null() {
+        metric(
+            &mut out,
+            "dmi_firmware_info",
+            "Firmware vendor, version and release date.",
+            vec![(
+                labels(
+                    firmware,
+                    &[
+                        ("vendor", "vendor"),
+                        ("version", "firmware_version"),
+                        ("date", "firmware_release_date"),
+                        ("release", "platform_firmware_release"),
+                    ],
+                ),
+                1.0,
+            )],
+        );
+    }
+
+    let system = &model["system"];
+    if !system.is_null() {
+        metric(
+            &mut out,
+            "dmi_system_info",
+            "System manufacturer, product and identifiers.",
+            vec![(
+                labels(
+                    system,
+                    &[
+                        ("manufacturer", "manufacturer"),
+                        ("product_name", "product_name"),
+                        ("version", "version"),
+                        ("serial_number", "serial_number"),
+                        ("uuid", "uuid"),
+                        ("sku", "sku"),
+                        ("family", "family"),
+                    ],
+                ),
+                1.0,
+            )],
+        );
+    }
+
+    let baseboard = &model["baseboard"];
+    if !baseboard.is_null() {
+        metric(
+            &mut out,
+            "dmi_baseboard_info",
+            "Baseboard manufacturer, product and identifiers.",
+            vec![(
+                labels(
+                    baseboard,
+                    &[
+                        ("manufacturer", "manufacturer"),
+                        ("product", "product"),
+                        ("version", "version"),
+                        ("serial_number", "serial_number"),
+                        ("asset_tag", "asset_tag"),
+                    ],
+                ),
+                1.0,
+            )],
+        );
+    }
+
+    let chassis = &model["chassis"];
+    if !chassis.is_null() {
+        metric(
+            &mut out,
+            "dmi_chassis_info",
+            "Chassis manufacturer, type and identifiers.",
+            vec![(
+                labels(
+                    chassis,
+                    &[
+                        ("manufacturer", "manufacturer"),
+                        ("type", "chassis_type"),
+                        ("serial_number", "serial_number"),
+                        ("asset_tag", "asset_tag_number"),
+                    ],
+                ),
+                1.0,
+            )],
+        );
+
+        let mut samples = Vec::new();
+        for (sensor, key) in [
+            ("bootup", "bootup_state"),
+            ("power_supply", "power_supply_state"),
+            ("thermal", "thermal_state"),
+        ] {
+            let current = to_text(&chassis[key]);
+            for state in CHASSIS_STATES {
+                samples.push((
+                    vec![("sensor", sensor.to_string()), ("state", state.to_string())],
+                    if current == *state { 1.0 } else { 0.0 },
+                ));
+            }
+        }
+        metric(
+            &mut out,
+            "dmi_chassis_state",
+            "Chassis state reported at last boot, one series per possible state.",
+            samples,
+        );
+    }
+
+    let processors = list(&model["processors"]);
+    // Sockets can share a designation, so the index keeps the series apart.
+    let socket = |i: usize, p: &Value| {
+        vec![
+            ("index", i.to_string()),
+            ("socket", to_text(&p["socket_designation"])),
+        ]
+    };
+    metric(
+        &mut out,
+        "dmi_processor_info",
+        "Processor manufacturer, version and family.",
+        processors
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let mut l = socket(i, p);
+                l.extend(labels(
+                    p,
+                    &[
+                        ("manufacturer", "manufacturer"),
+                        ("version", "version"),
+                        ("family", "family"),
+                    ],
+                ));
+                (l, 1.0)
+            })
+            .collect(),
+    );
+    for (name, help, key, scale) in [
+        (
+            "dmi_processor_cores",
+            "Number of cores per socket.",
+            "core_count",
+            1.0,
+        ),
+        (
+            "dmi_processor_cores_enabled",
+            "Number of enabled cores per socket.",
+            "core_enabled",
+            1.0,
+        ),
+        (
+            "dmi_processor_threads",
+            "Number of threads per socket.",
+            "thread_count",
+            1.0,
+        ),
+        (
+            "dmi_processor_max_speed_hertz",
+            "Maximum processor speed supported by the system.",
+            "max_speed",
+            1e6,
+        ),
+        (
+            "dmi_processor_current_speed_hertz",
+            "Processor speed at boot.",
+            "current_speed",
+            1e6,
+        ),
+    ] {
+        metric(
+            &mut out,
+            name,
+            help,
+            processors
+                .iter()
+                .enumerate()
+                .filter_map(|(i, p)| p[key].as_f64().map(|v| (socket(i, p), v * scale)))
+                .collect(),
+        );
+    }
+
+    let memory = &model["memory"];
+    if let Some(count) = memory["array"]["number_memory_devices"].as_f64() {
+        metric(
+            &mut out,
+            "dmi_memory_array_devices",
+            "Number of memory device sockets or chips in the physical memory array.",
+            vec![(Vec::new(), count)],
+        );
+    }
+    let devices = list(&memory["devices"]);
+    let device_labels = |d: &Value| {
+        labels(
+            d,
+            &[
+                ("locator", "device_locator"),
+                ("bank_locator", "bank_locator"),
+                ("manufacturer", "manufacturer"),
+                ("part_number", "part_number"),
+                ("serial_number", "serial_number"),
+                ("type", "memory_type"),
+            ],
+        )
+    };
+    metric(
+        &mut out,
+        "dmi_memory_device_size_bytes",
+        "Size of the memory device, 0 when the socket is empty.",
+        devices
+            .iter()
+            .filter_map(|d| {
+                d["size_mb"]
+                    .as_f64()
+                    .map(|mb| (device_labels(d), mb * 1024.0 * 1024.0))
+            })
+            .collect(),
+    );
+    for (name, help, key) in [
+        (
+            "dmi_memory_device_speed_mts",
+            "Maximum speed of the memory device in MT/s.",
+            "speed",
+        ),
+        (
+            "dmi_memory_device_configured_speed_mts",
+            "Configured speed of the memory device in MT/s.",
+            "configured_speed",
+        ),
+    ] {
+        metric(
+            &mut out,
+            name,
+            help,
+            devices
+                .iter()
+                .filter_map(|d| {
+                    d[key].as_f64().map(|v| {
+                        (
+                            labels(
+                                d,
+                                &[
+                                    ("locator", "device_locator"),
+                                    ("bank_locator", "bank_locator"),
+                                ],
+                            ),
+                            v,
+                        )
+                    })
+                })
+                .collect(),
+        );
+    }
+
+    metric(
+        &mut out,
+        "dmi_slot_in_use",
+        "Whether the system slot is in use.",
+        list(&model["slots"])
+            .iter()
+            .map(|s| {
+                (
+                    labels(s, &[("designation", "designation"), ("type", "slot_type")]),
+                    if s["current_usage"] == "In Use" {
+                        1.0
+                    } else {
+                        0.0
+                    },
+                )
+            })
+            .collect(),
+    );
+
+    let batteries = list(&model["batteries"]);
+    let location = |b: &Value| vec![("location", to_text(&b["location"]))];
+    metric(
+        &mut out,
+        "dmi_battery_info",
+        "Portable battery manufacturer, name and chemistry.",
+        batteries
+            .iter()
+            .map(|b| {
+                (
+                    labels(
+                        b,
+                        &[
+                            ("location", "location"),
+                            ("manufacturer", "manufacturer"),
+                            ("name", "device_name"),
+                            ("chemistry", "device_chemistry"),
+                            ("serial_number", "serial_number"),
+                        ],
+                    ),
+                    1.0,
+                )
+            })
+            .collect(),
+    );
+    metric(
+        &mut out,
+        "dmi_battery_design_capacity_watt_hours",
+        "Design capacity of the battery.",
+        batteries
+            .iter()
+            .filter_map(|b| {
+                b["design_capacity"]
+                    .as_f64()
+                    .map(|mwh| (location(b), mwh / 1000.0))
+            })
+            .collect(),
+    );
+    metric(
+        &mut out,
+        "dmi_battery_design_voltage_volts",
+        "Design voltage of the battery.",
+        batteries
+            .iter()
+            .filter_map(|b| {
+                b["design_voltage"]
+                    .as_f64()
+                    .map(|mv| (location(b), mv / 1000.0))
+            })
+            .collect(),
+    );
+    metric(
+        &mut out,
+        "dmi_battery_full_capacity_watt_hours",
+        "Current full charge capacity of the battery, from sysfs.",
+        batteries
+            .iter()
+            .filter_map(|b| {
+                b["live"]["full_capacity"]
+                    .as_f64()
+                    .map(|mwh| (location(b), mwh / 1000.0))
+            })
+            .collect(),
+    );
+
+    out
+}
+
+fn labels(value: &Value, keys: &[(&'static str, &str)]) -> Labels {
+    keys.iter()
+        .map(|(label, key)| (*label, to_text(&value[*key])))
+        .collect()
+}
+
+fn metric(out: &mut String, name: &str, help: &str, samples: Vec<(Labels, f64)>) {
+    if samples.is_empty() {
+        return;
+    }
+
+    writeln!(out, "# HELP {name} {help}").unwrap();
+    writeln!(out, "# TYPE {name} gauge").unwrap();
+    for (labels, value) in samples {
+        if labels.is_empty() {
+            writeln!(out, "{name} {value}").unwrap();
+        } else {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|(k, v)| format!("{k}=\"{}\"", escape(v)))
+                .collect();
+            writeln!(out, "{name}{{{}}} {value}", labels.join(",")).unwrap();
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,144 @@
+// A copy of the table with serial numbers, asset tags and UUIDs replaced, for
+// attaching to public bug reports.
+//
+// Every replacement has the length of what it replaces, so offsets, string
+// sets and structure lengths are untouched and the copy decodes exactly like
+// the original. Equal values get equal placeholders, so a serial shared by
+// two structures still matches after redaction. Placeholder strings such as
+// "Not Specified" are kept as they are.
+
+use std::collections::HashMap;
+
+use crate::dmi::table::Structure;
+
+// Offsets of the string references to redact, by structure type.
+fn string_fields(type_id: u8) -> &'static [usize] {
+    match type_id {
+        1 => &[0x07],           // System: serial number
+        2 | 3 => &[0x07, 0x08], // Baseboard, chassis: serial number, asset tag
+        4 => &[0x20, 0x21],     // Processor: serial number, asset tag
+        17 => &[0x18, 0x19],    // Memory device: serial number, asset tag
+        22 => &[0x07],          // Portable battery: serial number
+        39 => &[0x08, 0x09],    // Power supply: serial number, asset tag
+        _ => &[],
+    }
+}
+
+// Offset and length of the raw fields to redact, by structure type.
+fn byte_fields(type_id: u8) -> &'static [(usize, usize)] {
+    match type_id {
+        1 => &[(0x08, 16)], // System: UUID
+        22 => &[(0x10, 2)], // Portable battery: SBDS serial number
+        _ => &[],
+    }
+}
+
+const KEPT: [&str; 8] = [
+    "Not Specified",
+    "Not Available",
+    "To Be Filled By O.E.M.",
+    "Default string",
+    "Unknown",
+    "None",
+    "N/A",
+    "0",
+];
+
+#[derive(Default)]
+struct Placeholders {
+    // Number of every value replaced so far, in order of appearance.
+    seen: HashMap<Vec<u8>, usize>,
+}
+
+impl Placeholders {
+    fn number(&mut self, value: &[u8]) -> usize {
+        let next = self.seen.len() + 1;
+        *self.seen.entry(value.to_vec()).or_insert(next)
+    }
+
+    // "XXXXXX1F": the value's number in base 36, padded with X to its length.
+    fn string(&mut self, value: &[u8]) -> Vec<u8> {
+        let mut n = self.number(value);
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"[n % 36]);
+            n /= 36;
+        }
+        digits.resize(value.len().max(digits.len()), b'X');
+        digits.truncate(value.len());
+        digits.reverse();
+        digits
+    }
+
+    // The value's number, big endian, in a field of the same size.
+    fn bytes(&mut self, value: &[u8]) -> Vec<u8> {
+        let n = (self.number(value) as u64).to_be_bytes();
+        let mut bytes = vec![0; value.len()];
+        let len = n.len().min(bytes.len());
+        let end = bytes.len();
+        bytes[end - len..].copy_from_slice(&n[n.len() - len..]);
+        bytes
+    }
+}
+
+pub fn render(structures: &[Structure]) -> Vec<u8> {
+    let mut placeholders = Placeholders::default();
+    let mut table = Vec::new();
+
+    for structure in structures {
+        let mut bytes = structure.bytes.clone();
+        let length = structure.header.length as usize;
+
+        for &(offset, size) in byte_fields(structure.type_id()) {
+            let Some(field) = bytes
+                .get(offset..offset + size)
+                .filter(|_| offset + size <= length)
+            else {
+                continue;
+            };
+            // All zeros or all ones mean "not present" or "not set".
+            if field.iter().all(|b| *b == 0) || field.iter().all(|b| *b == 0xFF) {
+                continue;
+            }
+            let replacement = placeholders.bytes(field);
+            bytes[offset..offset + size].copy_from_slice(&replacement);
+        }
+
+        let mut indexes: Vec<u8> = string_fields(structure.type_id())
+            .iter()
+            .filter(|&&offset| offset < length)
+            .map(|&offset| bytes[offset])
+            .filter(|&index| index != 0)
+            .collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        for index in indexes {
+            let Some((start, end)) = string_span(&bytes[length..], index) else {
+                continue;
+            };
+            let (start, end) = (length + start, length + end);
+            let value = &bytes[start..end];
+            let text = String::from_utf8_lossy(value);
+            if text.trim().is_empty() || KEPT.contains(&text.trim()) {
+                continue;
+            }
+            let replacement = placeholders.string(value);
+            bytes[start..end].copy_from_slice(&replacement);
+        }
+
+        table.extend_from_slice(&bytes);
+    }
+
+    table
+}
+
+// Start and end offsets of string `index` (1-based) in a string-set.
+fn string_span(strings: &[u8], index: u8) -> Option<(usize, usize)> {
+    let mut start = 0;
+    for _ in 1..index {
+        start += strings.get(start..)?.iter().position(|b| *b == 0)? + 1;
+    }
+    let len = strings.get(start..)?.iter().position(|b| *b == 0)?;
+    (len > 0).then_some((start, start + len))
+}
@@ -0,0 +1,460 @@
+// Redfish resources built from the decoded model, for hosts without a BMC.
+//
+// The output is a single JSON object keyed by `@odata.id`, laid out like a
+// Redfish service mockup: the ComputerSystem with its Processor and Memory
+// collections, and the Chassis with its PCIeSlots. Enumerations are mapped to
+// the Redfish values where there is an equivalent and omitted otherwise.
+
+use serde_json::{Map, Value, json};
+
+use super::{known, list};
+use crate::query::to_text;
+
+const SYSTEM: &str = "/redfish/v1/Systems/1";
+const CHASSIS: &str = "/redfish/v1/Chassis/1";
+
+pub fn render(model: &Value) -> String {
+    let mut resources = Map::new();
+
+    let processors = list(&model["processors"]);
+    let devices = list(&model["memory"]["devices"]);
+    let error_correction = error_correction(&model["memory"]["array"]["error_correction"]);
+
+    let mut taken = Vec::new();
+    let processor_members: Vec<(String, Value)> = processors
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let id = resource_id(&p["socket_designation"], "CPU", i, &mut taken);
+            (format!("{SYSTEM}/Processors/{id}"), processor(p, &id))
+        })
+        .collect();
+    let mut taken = Vec::new();
+    let memory_members: Vec<(String, Value)> = devices
+        .iter()
+        .enumerate()
+        .map(|(i, d)| {
+            let id = resource_id(&d["device_locator"], "DIMM", i, &mut taken);
+            (
+                format!("{SYSTEM}/Memory/{id}"),
+                memory(d, &id, error_correction),
+            )
+        })
+        .collect();
+
+    let mut system = object([
+        (
+            "@odata.type",
+            json!("#ComputerSystem.v1_22_0.ComputerSystem"),
+        ),
+        ("@odata.id", json!(SYSTEM)),
+        ("Id", json!("1")),
+        ("Name", json!("Computer System")),
+        ("SystemType", json!("Physical")),
+    ]);
+    let s = &model["system"];
+    insert(&mut system, "Manufacturer", known(&s["manufacturer"]));
+    insert(&mut system, "Model", known(&s["product_name"]));
+    insert(&mut system, "SKU", known(&s["sku"]));
+    insert(&mut system, "SerialNumber", known(&s["serial_number"]));
+    insert(&mut system, "UUID", known(&s["uuid"]));
+    insert(
+        &mut system,
+        "BiosVersion",
+        known(&model["firmware"]["firmware_version"]),
+    );
+    insert(
+        &mut system,
+        "AssetTag",
+        known(&model["chassis"]["asset_tag_number"]),
+    );
+
+    let populated: Vec<&Value> = processors
+        .iter()
+        .filter(|p| !to_text(&p["status"]).starts_with("Unpopulated"))
+        .collect();
+    let mut summary = object([("Count", json!(populated.len()))]);
+    insert(
+        &mut summary,
+        "Model",
+        populated.first().and_then(|p| known(&p["version"])),
+    );
+    insert(&mut summary, "CoreCount", sum(&populated, "core_count"));
+    insert(
+        &mut summary,
+        "LogicalProcessorCount",
+        sum(&populated, "thread_count"),
+    );
+    system.insert("ProcessorSummary".into(), Value::Object(summary));
+
+    let total_mb: u64 = devices.iter().filter_map(|d| d["size_mb"].as_u64()).sum();
+    system.insert(
+        "MemorySummary".into(),
+        json!({ "TotalSystemMemoryGiB": total_mb as f64 / 1024.0 }),
+    );
+    system.insert("Processors".into(), link(&format!("{SYSTEM}/Processors")));
+    system.insert("Memory".into(), link(&format!("{SYSTEM}/Memory")));
+    system.insert("Links".into(), json!({ "Chassis": [link(CHASSIS)] }));
+    resources.insert(SYSTEM.into(), Value::Object(system));
+
+    collection(
+        &mut resources,
+        &format!("{SYSTEM}/Processors"),
+        "#ProcessorCollection.ProcessorCollection",
+        "Processors Collection",
+        processor_members,
+    );
+    collection(
+        &mut resources,
+        &format!("{SYSTEM}/Memory"),
+        "#MemoryCollection.MemoryCollection",
+        "Memory Collection",
+        memory_members,
+    );
+
+    let mut chassis = object([
+        ("@odata.type", json!("#Chassis.v1_25_0.Chassis")),
+        ("@odata.id", json!(CHASSIS)),
+        ("Id", json!("1")),
+        ("Name", json!("Chassis")),
+    ]);
+    let c = &model["chassis"];
+    insert(
+        &mut chassis,
+        "ChassisType",
+        Some(chassis_type(&c["chassis_type"]).to_string()),
+    );
+    insert(&mut chassis, "Manufacturer", known(&c["manufacturer"]));
+    insert(&mut chassis, "Model", known(&model["baseboard"]["product"]));
+    insert(&mut chassis, "Version", known(&c["version"]));
+    insert(&mut chassis, "SerialNumber", known(&c["serial_number"]));
+    insert(&mut chassis, "AssetTag", known(&c["asset_tag_number"]));
+    insert(&mut chassis, "SKU", known(&c["sku_number"]));
+    if let Some(health) = c["bootup_state"].as_str().and_then(health) {
+        chassis.insert(
+            "Status".into(),
+            json!({ "State": "Enabled", "Health": health }),
+        );
+    }
+    chassis.insert("PCIeSlots".into(), link(&format!("{CHASSIS}/PCIeSlots")));
+    chassis.insert("Links".into(), json!({ "ComputerSystems": [link(SYSTEM)] }));
+    resources.insert(CHASSIS.into(), Value::Object(chassis));
+
+    let slots: Vec<Value> = list(&model["slots"])
+        .iter()
+        .filter(|s| to_text(&s["slot_type"]).starts_with("PCI Express"))
+        .map(pcie_slot)
+        .collect();
+    resources.insert(
+        format!("{CHASSIS}/PCIeSlots"),
+        json!({
+            "@odata.type": "#PCIeSlots.v1_6_0.PCIeSlots",
+            "@odata.id": format!("{CHASSIS}/PCIeSlots"),
+            "Id": "PCIeSlots",
+            "Name": "PCIe Slots",
+            "Slots": slots,
+        }),
+    );
+
+    serde_json::to_string_pretty(&resources).expect("Redfish resources are always serializable")
+}
+
+fn processor(p: &Value, id: &str) -> Value {
+    let mut r = object([
+        ("@odata.type", json!("#Processor.v1_20_0.Processor")),
+        ("@odata.id", json!(format!("{SYSTEM}/Processors/{id}"))),
+        ("Id", json!(id)),
+        ("Name", json!(to_text(&p["socket_designation"]))),
+    ]);
+    insert(&mut r, "Socket", known(&p["socket_designation"]));
+    insert(
+        &mut r,
+        "ProcessorType",
+        processor_type(&p["processor_type"]).map(str::to_string),
+    );
+    insert(&mut r, "Manufacturer", known(&p["manufacturer"]));
+    insert(&mut r, "Model", known(&p["version"]));
+    insert(&mut r, "MaxSpeedMHz", number(&p["max_speed"]));
+    insert(&mut r, "OperatingSpeedMHz", number(&p["current_speed"]));
+    insert(&mut r, "TotalCores", number(&p["core_count"]));
+    insert(&mut r, "TotalEnabledCores", number(&p["core_enabled"]));
+    insert(&mut r, "TotalThreads", number(&p["thread_count"]));
+    insert(&mut r, "SerialNumber", known(&p["serial_number"]));
+    insert(&mut r, "PartNumber", known(&p["part_number"]));
+    insert(&mut r, "AssetTag", known(&p["asset_tag"]));
+
+    let status = to_text(&p["status"]);
+    let state = if status.starts_with("Unpopulated") {
+        "Absent"
+    } else if status.ends_with("Enabled") {
+        "Enabled"
+    } else {
+        "Disabled"
+    };
+    r.insert("Status".into(), json!({ "State": state }));
+
+    Value::Object(r)
+}
+
+fn memory(d: &Value, id: &str, error_correction: Option<&str>) -> Value {
+    let mut r = object([
+        ("@odata.type", json!("#Memory.v1_19_0.Memory")),
+        ("@odata.id", json!(format!("{SYSTEM}/Memory/{id}"))),
+        ("Id", json!(id)),
+        ("Name", json!(to_text(&d["device_locator"]))),
+    ]);
+    insert(
+        &mut r,
+        "MemoryDeviceType",
+        memory_device_type(&d["memory_type"]).map(str::to_string),
+    );
+    insert(
+        &mut r,
+        "MemoryType",
+        memory_type(&d["memory_technology"]).map(str::to_string),
+    );
+    insert(
+        &mut r,
+        "BaseModuleType",
+        base_module_type(&d["form_factor"]).map(str::to_string),
+    );
+    insert(&mut r, "CapacityMiB", number(&d["size_mb"]));
+    insert(
+        &mut r,
+        "AllowedSpeedsMHz",
+        number(&d["speed"]).map(|s| vec![s]),
+    );
+    insert(&mut r, "OperatingSpeedMhz", number(&d["configured_speed"]));
+    insert(&mut r, "RankCount", number(&d["rank"]));
+    insert(
+        &mut r,
+        "ErrorCorrection",
+        error_correction.map(str::to_string),
+    );
+    insert(&mut r, "Manufacturer", known(&d["manufacturer"]));
+    insert(&mut r, "SerialNumber", known(&d["serial_number"]));
+    insert(&mut r, "PartNumber", known(&d["part_number"]));
+    if let Some(locator) = known(&d["device_locator"]) {
+        r.insert(
+            "Location".into(),
+            json!({ "PartLocation": { "ServiceLabel": locator, "LocationType": "Slot" } }),
+        );
+    }
+
+    let state = if d["size_mb"] == 0 {
+        "Absent"
+    } else {
+        "Enabled"
+    };
+    r.insert("Status".into(), json!({ "State": state }));
+
+    Value::Object(r)
+}
+
+fn pcie_slot(s: &Value) -> Value {
+    let slot_type = to_text(&s["slot_type"]);
+    let mut r = Map::new();
+    r.insert(
+        "Location".into(),
+        json!({ "PartLocation": { "ServiceLabel": to_text(&s["designation"]), "LocationType": "Slot" } }),
+    );
+
+    // "PCI Express 4 x16" carries the generation; "PCI Express x16" does not.
+    let generation = slot_type
+        .trim_start_matches("PCI Express")
+        .split_whitespace()
+        .next()
+        .filter(|g| !g.starts_with('x'))
+        .map(|g| format!("Gen{}", g.trim_end_matches('+')));
+    insert(&mut r, "PCIeType", generation);
+    insert(
+        &mut r,
+        "Lanes",
+        lanes(&slot_type).or_else(|| lanes(&to_text(&s["bus_width"]))),
+    );
+    let length = match to_text(&s["length"]).as_str() {
+        "Long" => Some("FullLength"),
+        "Short" => Some("HalfLength"),
+        _ => None,
+    };
+    insert(&mut r, "SlotType", length.map(str::to_string));
+    let state = match to_text(&s["current_usage"]).as_str() {
+        "In Use" => "Enabled",
+        "Available" => "Absent",
+        _ => "Disabled",
+    };
+    r.insert("Status".into(), json!({ "State": state }));
+
+    Value::Object(r)
+}
+
+fn collection(
+    resources: &mut Map<String, Value>,
+    id: &str,
+    odata_type: &str,
+    name: &str,
+    members: Vec<(String, Value)>,
+) {
+    let links: Vec<Value> = members.iter().map(|(uri, _)| link(uri)).collect();
+    resources.insert(
+        id.to_string(),
+        json!({
+            "@odata.type": odata_type,
+            "@odata.id": id,
+            "Name": name,
+            "Members@odata.count": links.len(),
+            "Members": links,
+        }),
+    );
+    resources.extend(members);
+}
+
+fn processor_type(value: &Value) -> Option<&'static str> {
+    match value.as_str()? {
+        "Central Processor" => Some("CPU"),
+        "Video Processor" => Some("GPU"),
+        "DSP Processor" => Some("DSP"),
+        "Math Processor" => Some("Accelerator"),
+        _ => None,
+    }
+}
+
+fn memory_device_type(value: &Value) -> Option<&'static str> {
+    match value.as_str()? {
+        "DDR" => Some("DDR"),
+        "DDR2" => Some("DDR2"),
+        "DDR3" => Some("DDR3"),
+        "DDR4" => Some("DDR4"),
+        "DDR5" => Some("DDR5"),
+        "LPDDR3" => Some("LPDDR3_SDRAM"),
+        "LPDDR4" => Some("LPDDR4_SDRAM"),
+        "LPDDR5" => Some("LPDDR5_SDRAM"),
+        "HBM" => Some("HBM"),
+        "HBM2" => Some("HBM2"),
+        "HBM3" => Some("HBM3"),
+        "Logical non-volatile device" => Some("Logical"),
+        _ => None,
+    }
+}
+
+fn memory_type(value: &Value) -> Option<&'static str> {
+    match value.as_str()? {
+        "DRAM" => Some("DRAM"),
+        "NVDIMM-N" => Some("NVDIMM_N"),
+        "NVDIMM-F" => Some("NVDIMM_F"),
+        "NVDIMM-P" => Some("NVDIMM_P"),
+        "Intel Optane persistent memory" => Some("IntelOptane"),
+        _ => None,
+    }
+}
+
+fn base_module_type(value: &Value) -> Option<&'static str> {
+    match value.as_str()? {
+        "SODIMM" => Some("SO_DIMM"),
+        "Die" => Some("Die"),
+        _ => None,
+    }
+}
+
+fn error_correction(value: &Value) -> Option<&'static str> {
+    match value.as_str()? {
+        "None" => Some("NoECC"),
+        "Single-bit ECC" => Some("SingleBitECC"),
+        "Multi-bit ECC" => Some("MultiBitECC"),
+        "Parity" => Some("AddressParity"),
+        _ => None,
+    }
+}
+
+fn chassis_type(value: &Value) -> &'static str {
+    match value.as_str().map(str::trim) {
+        Some(
+            "Desktop"
+            | "Low Profile Desktop"
+            | "Pizza Box"
+            | "Mini Tower"
+            | "Tower"
+            | "All in One"
+            | "Space-saving"
+            | "Lunch Box"
+            | "Main Server Chassis"
+            | "Sealed-case PC"
+            | "Mini PC"
+            | "Stick PC"
+            | "Embedded PC",
+        ) => "StandAlone",
+        Some("Rack Mount Chassis") => "RackMount",
+        Some("Blade") => "Blade",
+        Some("Blade Enclosure" | "Multi-system chassis") => "Enclosure",
+        Some("Expansion Chassis" | "Bus Expansion Chassis") => "Expansion",
+        Some("SubChassis") => "Module",
+        Some("RAID Chassis") => "StorageEnclosure",
+        Some("Compact PCI" | "Advanced TCA") => "Card",
+        _ => "Other",
+    }
+}
+
+fn health(state: &str) -> Option<&'static str> {
+    match state {
+        "Safe" => Some("OK"),
+        "Warning" => Some("Warning"),
+        "Critical" | "Non Recoverable" => Some("Critical"),
+        _ => None,
+    }
+}
+
+fn lanes(text: &str) -> Option<u64> {
+    text.rsplit_once('x').and_then(|(_, n)| n.parse().ok())
+}
+
+fn sum(processors: &[&Value], key: &str) -> Option<u64> {
+    processors.iter().map(|p| p[key].as_u64()).sum()
+}
+
+// A URI-safe resource Id, falling back to `prefix` and the position. Ids
+// already `taken` by an earlier member get the position appended, so two
+// DIMMs sharing a locator don't end up at the same URI.
+fn resource_id(value: &Value, prefix: &str, index: usize, taken: &mut Vec<String>) -> String {
+    let id: String = known(value)
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let mut id = if id.is_empty() {
+        format!("{prefix}{index}")
+    } else {
+        id
+    };
+    while taken.contains(&id) {
+        id = format!("{id}-{index}");
+    }
+    taken.push(id.clone());
+    id
+}
+
+fn link(uri: &str) -> Value {
+    json!({ "@odata.id": uri })
+}
+
+fn object<const N: usize>(fields: [(&str, Value); N]) -> Map<String, Value> {
+    fields
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect()
+}
+
+fn insert<T: Into<Value>>(map: &mut Map<String, Value>, key: &str, value: Option<T>) {
+    if let Some(value) = value {
+        map.insert(key.to_string(), value.into());
+    }
+}
+
+fn number(value: &Value) -> Option<u64> {
+    value.as_u64()
+}
@@ -0,0 +1,271 @@
+// Human readable hardware report, as Markdown or a self-contained HTML page.
+//
+// The report is generated from the decoded model: every object becomes a
+// section with a field table, objects whose fields are all flags become a
+// list of the enabled ones, and lists of devices become one sub-section each.
+
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+use crate::query::{humanize, to_text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+impl From<&str> for Format {
+    fn from(value: &str) -> Self {
+        match value {
+            "html" => Self::Html,
+            _ => Self::Markdown,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Section {
+    title: String,
+    anchor: String,
+    rows: Vec<(String, String)>,
+    items: Vec<String>,
+    children: Vec<Section>,
+}
+
+pub fn render(model: &Value, format: Format) -> String {
+    let title = match (
+        model
+            .pointer("/system/manufacturer")
+            .and_then(Value::as_str),
+        model
+            .pointer("/system/product_name")
+            .and_then(Value::as_str),
+    ) {
+        (Some(manufacturer), Some(product)) => format!("Hardware Report: {manufacturer} {product}"),
+        _ => "Hardware Report".to_string(),
+    };
+
+    let sections: Vec<Section> = model
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(key, value)| build_section(&humanize(key), key, value))
+        .collect();
+
+    match format {
+        Format::Markdown => markdown(&title, &sections),
+        Format::Html => html(&title, &sections),
+    }
+}
+
+fn build_section(title: &str, anchor: &str, value: &Value) -> Section {
+    let mut section = Section {
+        title: title.to_string(),
+        anchor: anchor.to_string(),
+        ..Default::default()
+    };
+
+    match value {
+        Value::Object(map) if is_flags(map) => {
+            section.items = map
+                .iter()
+                .filter(|(_, v)| v.as_bool() == Some(true))
+                .map(|(k, _)| humanize(k))
+                .collect();
+        }
+        Value::Object(map) => {
+            for (key, v) in map {
+                match v {
+                    Value::Array(list) if list.is_empty() => {}
+                    Value::Object(_) | Value::Array(_) => {
+                        let anchor = format!("{anchor}-{}", slug(key));
+                        section
+                            .children
+                            .push(build_section(&humanize(key), &anchor, v));
+                    }
+                    _ => section.rows.push((humanize(key), text(v))),
+                }
+            }
+        }
+        Value::Array(list) if list.iter().all(|v| !v.is_object()) => {
+            section.items = list.iter().map(to_text).collect();
+        }
+        Value::Array(list) => {
+            for (i, item) in list.iter().enumerate() {
+                let name = item_name(item).unwrap_or_else(|| format!("#{}", i + 1));
+                let anchor = format!("{anchor}-{i}");
+                section.children.push(build_section(&name, &anchor, item));
+            }
+        }
+        v => section.rows.push((title.to_string(), text(v))),
+    }
+
+    section
+}
+
+fn is_flags(map: &Map<String, Value>) -> bool {
+    !map.is_empty() && map.values().all(Value::is_boolean)
+}
+
+fn item_name(item: &Value) -> Option<String> {
+    ["socket_designation", "device_locator", "designation"]
+        .iter()
+        .find_map(|key| item.get(key).and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "Unknown".to_string(),
+        Value::Bool(true) => "Yes".to_string(),
+        Value::Bool(false) => "No".to_string(),
+        v => to_text(v),
+    }
+}
+
+fn slug(key: &str) -> String {
+    key.to_lowercase().replace(['_', ' '], "-")
+}
+
+fn markdown(title: &str, sections: &[Section]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# {title}\n").unwrap();
+
+    writeln!(out, "## Contents\n").unwrap();
+    for section in sections {
+        markdown_toc(&mut out, section, 0);
+    }
+
+    for section in sections {
+        markdown_section(&mut out, section, 2);
+    }
+
+    out
+}
+
+fn markdown_toc(out: &mut String, section: &Section, depth: usize) {
+    writeln!(
+        out,
+        "{}- [{}](#{})",
+        "  ".repeat(depth),
+        section.title,
+        section.anchor
+    )
+    .unwrap();
+    for child in &section.children {
+        markdown_toc(out, child, depth + 1);
+    }
+}
+
+fn markdown_section(out: &mut String, section: &Section, level: usize) {
+    writeln!(out, "\n<a id=\"{}\"></a>\n", section.anchor).unwrap();
+    writeln!(out, "{} {}\n", "#".repeat(level.min(6)), section.title).unwrap();
+
+    if !section.rows.is_empty() {
+        writeln!(out, "| Field | Value |").unwrap();
+        writeln!(out, "| --- | --- |").unwrap();
+        for (key, value) in &section.rows {
+            writeln!(out, "| {key} | {} |", value.replace('|', "\\|")).unwrap();
+        }
+    }
+
+    for item in &section.items {
+        writeln!(out, "- {item}").unwrap();
+    }
+
+    for child in &section.children {
+        markdown_section(out, child, level + 1);
+    }
+}
+
+const STYLE: &str = "body{font-family:sans-serif;max-width:60em;margin:2em auto;padding:0 1em;color:#222}\
+table{border-collapse:collapse;margin:0.5em 0 1em}\
+td{border:1px solid #ccc;padding:0.25em 0.75em}\
+td:first-child{font-weight:bold;background:#f5f5f5}\
+nav ul{list-style:none;padding-left:1.2em}";
+
+fn html(title: &str, sections: &[Section]) -> String {
+    let mut out = String::new();
+    writeln!(out, "<!DOCTYPE html>").unwrap();
+    writeln!(out, "<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">").unwrap();
+    writeln!(out, "<title>{}</title>", escape(title)).unwrap();
+    writeln!(out, "<style>{STYLE}</style>\n</head>\n<body>").unwrap();
+    writeln!(out, "<h1>{}</h1>", escape(title)).unwrap();
+
+    writeln!(out, "<nav>\n<h2>Contents</h2>").unwrap();
+    html_toc(&mut out, sections);
+    writeln!(out, "</nav>").unwrap();
+
+    for section in sections {
+        html_section(&mut out, section, 2);
+    }
+
+    writeln!(out, "</body>\n</html>").unwrap();
+    out
+}
+
+fn html_toc(out: &mut String, sections: &[Section]) {
+    writeln!(out, "<ul>").unwrap();
+    for section in sections {
+        writeln!(
+            out,
+            "<li><a href=\"#{}\">{}</a>",
+            section.anchor,
+            escape(&section.title)
+        )
+        .unwrap();
+        if !section.children.is_empty() {
+            html_toc(out, &section.children);
+        }
+        writeln!(out, "</li>").unwrap();
+    }
+    writeln!(out, "</ul>").unwrap();
+}
+
+fn html_section(out: &mut String, section: &Section, level: usize) {
+    let level = level.min(6);
+    writeln!(
+        out,
+        "<section>\n<h{level} id=\"{}\">{}</h{level}>",
+        section.anchor,
+        escape(&section.title)
+    )
+    .unwrap();
+
+    if !section.rows.is_empty() {
+        writeln!(out, "<table>").unwrap();
+        for (key, value) in &section.rows {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td></tr>",
+                escape(key),
+                escape(value)
+            )
+            .unwrap();
+        }
+        writeln!(out, "</table>").unwrap();
+    }
+
+    if !section.items.is_empty() {
+        writeln!(out, "<ul>").unwrap();
+        for item in &section.items {
+            writeln!(out, "<li>{}</li>", escape(item)).unwrap();
+        }
+        writeln!(out, "</ul>").unwrap();
+    }
+
+    for child in &section.children {
+        html_section(out, child, level + 1);
+    }
+    writeln!(out, "</section>").unwrap();
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
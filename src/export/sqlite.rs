@@ -0,0 +1,302 @@
+// Normalized inventory tables in a SQLite database.
+//
+// Every table starts with the same three key columns: the system UUID and
+// serial number identify the host, and `collected_at` tells repeated runs
+// apart. Runs only ever insert, so one database can collect a whole fleet:
+//
+//   SELECT d.system_serial FROM memory_devices d
+//   JOIN firmware f USING (system_uuid, system_serial, collected_at)
+//   WHERE d.part_number = 'M393A4K40DB3-CWE' AND f.version < '2.0';
+
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{Connection, params_from_iter, types::Value as SqlValue};
+use serde_json::Value;
+
+use super::{list, timestamp};
+
+struct Table {
+    name: &'static str,
+    // Column name, SQL type and JSON pointer into the row's value.
+    columns: &'static [(&'static str, &'static str, &'static str)],
+}
+
+const FIRMWARE: Table = Table {
+    name: "firmware",
+    columns: &[
+        ("vendor", "TEXT", "/vendor"),
+        ("version", "TEXT", "/firmware_version"),
+        ("release_date", "TEXT", "/firmware_release_date"),
+        ("release", "TEXT", "/platform_firmware_release"),
+        (
+            "embedded_controller_release",
+            "TEXT",
+            "/embedded_controller_firmware_release",
+        ),
+        ("rom_size", "TEXT", "/firmware_rom_size"),
+        (
+            "uefi",
+            "INTEGER",
+            "/firmware_characteristics_extensions/uefi_spec",
+        ),
+        (
+            "virtual_machine",
+            "INTEGER",
+            "/firmware_characteristics_extensions/virtual_machine",
+        ),
+    ],
+};
+
+const SYSTEM: Table = Table {
+    name: "system",
+    columns: &[
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("product_name", "TEXT", "/product_name"),
+        ("version", "TEXT", "/version"),
+        ("sku", "TEXT", "/sku"),
+        ("family", "TEXT", "/family"),
+        ("wakeup_type", "TEXT", "/wakeup_type"),
+    ],
+};
+
+const BASEBOARD: Table = Table {
+    name: "baseboard",
+    columns: &[
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("product", "TEXT", "/product"),
+        ("version", "TEXT", "/version"),
+        ("serial_number", "TEXT", "/serial_number"),
+        ("asset_tag", "TEXT", "/asset_tag"),
+        ("board_type", "TEXT", "/board_type"),
+        ("location_in_chassis", "TEXT", "/location_in_chassis"),
+    ],
+};
+
+const CHASSIS: Table = Table {
+    name: "chassis",
+    columns: &[
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("chassis_type", "TEXT", "/chassis_type"),
+        ("version", "TEXT", "/version"),
+        ("serial_number", "TEXT", "/serial_number"),
+        ("asset_tag", "TEXT", "/asset_tag_number"),
+        ("sku", "TEXT", "/sku_number"),
+        ("height_u", "INTEGER", "/height"),
+        ("power_cords", "INTEGER", "/number_power_cords"),
+        ("bootup_state", "TEXT", "/bootup_state"),
+        ("power_supply_state", "TEXT", "/power_supply_state"),
+        ("thermal_state", "TEXT", "/thermal_state"),
+        ("security_status", "TEXT", "/security_status"),
+    ],
+};
+
+const PROCESSORS: Table = Table {
+    name: "processors",
+    columns: &[
+        ("socket", "TEXT", "/socket_designation"),
+        ("processor_type", "TEXT", "/processor_type"),
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("family", "TEXT", "/family"),
+        ("version", "TEXT", "/version"),
+        ("status", "TEXT", "/status"),
+        ("max_speed_mhz", "INTEGER", "/max_speed"),
+        ("current_speed_mhz", "INTEGER", "/current_speed"),
+        ("core_count", "INTEGER", "/core_count"),
+        ("core_enabled", "INTEGER", "/core_enabled"),
+        ("thread_count", "INTEGER", "/thread_count"),
+        ("serial_number", "TEXT", "/serial_number"),
+        ("part_number", "TEXT", "/part_number"),
+        ("asset_tag", "TEXT", "/asset_tag"),
+    ],
+};
+
+// One row per cache of each processor, built in `write`.
+const CACHES: Table = Table {
+    name: "caches",
+    columns: &[
+        ("socket", "TEXT", "/socket"),
+        ("level", "INTEGER", "/level"),
+        ("handle", "INTEGER", "/cache/handle"),
+        ("cache_type", "TEXT", "/cache/cache_type"),
+        ("installed_size_kb", "INTEGER", "/cache/installed_size_kb"),
+    ],
+};
+
+const MEMORY_ARRAYS: Table = Table {
+    name: "memory_arrays",
+    columns: &[
+        ("location", "TEXT", "/location"),
+        ("function", "TEXT", "/function"),
+        ("error_correction", "TEXT", "/error_correction"),
+        ("max_capacity", "TEXT", "/max_capacity"),
+        ("number_memory_devices", "INTEGER", "/number_memory_devices"),
+    ],
+};
+
+const MEMORY_DEVICES: Table = Table {
+    name: "memory_devices",
+    columns: &[
+        ("locator", "TEXT", "/device_locator"),
+        ("bank_locator", "TEXT", "/bank_locator"),
+        ("size_mb", "INTEGER", "/size_mb"),
+        ("form_factor", "TEXT", "/form_factor"),
+        ("memory_type", "TEXT", "/memory_type"),
+        ("memory_technology", "TEXT", "/memory_technology"),
+        ("speed_mts", "INTEGER", "/speed"),
+        ("configured_speed_mts", "INTEGER", "/configured_speed"),
+        ("rank", "INTEGER", "/rank"),
+        ("configured_voltage_mv", "INTEGER", "/configured_voltage_mv"),
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("serial_number", "TEXT", "/serial_number"),
+        ("part_number", "TEXT", "/part_number"),
+        ("asset_tag", "TEXT", "/asset_tag"),
+    ],
+};
+
+const SLOTS: Table = Table {
+    name: "slots",
+    columns: &[
+        ("designation", "TEXT", "/designation"),
+        ("slot_type", "TEXT", "/slot_type"),
+        ("bus_width", "TEXT", "/bus_width"),
+        ("current_usage", "TEXT", "/current_usage"),
+        ("length", "TEXT", "/length"),
+        ("slot_id", "INTEGER", "/id"),
+        ("bus_address", "TEXT", "/bdf"),
+    ],
+};
+
+const BATTERIES: Table = Table {
+    name: "batteries",
+    columns: &[
+        ("location", "TEXT", "/location"),
+        ("manufacturer", "TEXT", "/manufacturer"),
+        ("manufacture_date", "TEXT", "/manufacture_date"),
+        ("serial_number", "TEXT", "/serial_number"),
+        ("device_name", "TEXT", "/device_name"),
+        ("device_chemistry", "TEXT", "/device_chemistry"),
+        ("design_capacity_mwh", "INTEGER", "/design_capacity"),
+        ("design_voltage_mv", "INTEGER", "/design_voltage"),
+        ("sbds_version", "TEXT", "/sbds_version"),
+    ],
+};
+
+pub fn write(model: &Value, path: &Path) -> Result<()> {
+    let mut conn = Connection::open(path)?;
+    let tx = conn.transaction()?;
+
+    let key = [
+        text(&model["system"]["uuid"]),
+        text(&model["system"]["serial_number"]),
+        SqlValue::Text(timestamp()),
+    ];
+
+    let processors = list(&model["processors"]);
+    let caches: Vec<Value> = processors
+        .iter()
+        .flat_map(|p| {
+            [1, 2, 3].into_iter().filter_map(move |level| {
+                let cache = &p[format!("l{level}_cache")];
+                (!cache.is_null()).then(|| {
+                    serde_json::json!({
+                        "socket": p["socket_designation"],
+                        "level": level,
+                        "cache": cache,
+                    })
+                })
+            })
+        })
+        .collect();
+
+    let tables: [(&Table, Vec<&Value>); 10] = [
+        (&FIRMWARE, single(&model["firmware"])),
+        (&SYSTEM, single(&model["system"])),
+        (&BASEBOARD, single(&model["baseboard"])),
+        (&CHASSIS, single(&model["chassis"])),
+        (&PROCESSORS, processors.iter().collect()),
+        (&CACHES, caches.iter().collect()),
+        (&MEMORY_ARRAYS, single(&model["memory"]["array"])),
+        (
+            &MEMORY_DEVICES,
+            list(&model["memory"]["devices"]).iter().collect(),
+        ),
+        (&SLOTS, list(&model["slots"]).iter().collect()),
+        (&BATTERIES, list(&model["batteries"]).iter().collect()),
+    ];
+
+    for (table, rows) in tables {
+        let columns: Vec<String> = table
+            .columns
+            .iter()
+            .map(|(name, kind, _)| format!("{name} {kind}"))
+            .collect();
+        tx.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {name} (\
+             system_uuid TEXT, system_serial TEXT, collected_at TEXT, {columns});\
+             CREATE INDEX IF NOT EXISTS {name}_system ON {name} (system_uuid, system_serial);",
+            name = table.name,
+            columns = columns.join(", "),
+        ))?;
+
+        // A database written by an older build lacks the columns added since.
+        let existing: Vec<String> = tx
+            .prepare(&format!("PRAGMA table_info({})", table.name))?
+            .query_map([], |row| row.get(1))?
+            .collect::<rusqlite::Result<_>>()?;
+        for (name, kind, _) in table.columns {
+            if !existing.iter().any(|c| c == name) {
+                tx.execute_batch(&format!(
+                    "ALTER TABLE {} ADD COLUMN {name} {kind}",
+                    table.name
+                ))?;
+            }
+        }
+
+        let names: Vec<&str> = ["system_uuid", "system_serial", "collected_at"]
+            .into_iter()
+            .chain(table.columns.iter().map(|(name, _, _)| *name))
+            .collect();
+        let placeholders = vec!["?"; names.len()].join(", ");
+        let mut insert = tx.prepare(&format!(
+            "INSERT INTO {} ({}) VALUES ({placeholders})",
+            table.name,
+            names.join(", ")
+        ))?;
+        for row in rows {
+            let values = key.iter().cloned().chain(
+                table
+                    .columns
+                    .iter()
+                    .map(|(_, _, pointer)| sql(row.pointer(pointer).unwrap_or(&Value::Null))),
+            );
+            insert.execute(params_from_iter(values))?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+fn single(value: &Value) -> Vec<&Value> {
+    if value.is_null() { vec![] } else { vec![value] }
+}
+
+fn text(value: &Value) -> SqlValue {
+    match value {
+        Value::String(s) => SqlValue::Text(s.clone()),
+        _ => SqlValue::Null,
+    }
+}
+
+fn sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Null | Value::Array(_) | Value::Object(_) => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+    }
+}
@@ -0,0 +1,365 @@
+// Fleet overview of a directory of dumps.
+//
+// Hosts are grouped by system product, firmware version, DIMM part number
+// and processor model, one tab each. Enter on a group lists its hosts, and
+// Enter on a host hands its table back to the caller to open the per-host
+// view.
+
+use std::{fs, path::Path};
+
+use anyhow::{Result, bail};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Padding, Row, Table, TableState},
+};
+use serde_json::Value;
+
+use crate::{
+    dmi::{DMI, table},
+    export::{known, list},
+};
+
+#[derive(Debug)]
+struct Host {
+    name: String,
+    table: Vec<u8>,
+    model: Value,
+}
+
+struct Dimension {
+    title: &'static str,
+    // Column header for the number of devices, for per-device dimensions.
+    unit: Option<&'static str>,
+    // One value per device of a host, or a single one per host.
+    values: fn(&Value) -> Vec<String>,
+}
+
+const DIMENSIONS: [Dimension; 4] = [
+    Dimension {
+        title: "Products",
+        unit: None,
+        values: |m| vec![joined(&m["system"], "manufacturer", "product_name")],
+    },
+    Dimension {
+        title: "Firmware",
+        unit: None,
+        values: |m| vec![joined(&m["firmware"], "vendor", "firmware_version")],
+    },
+    Dimension {
+        title: "DIMM Parts",
+        unit: Some("DIMMs"),
+        values: |m| {
+            list(&m["memory"]["devices"])
+                .iter()
+                .filter(|d| d["size_mb"].as_u64().is_some_and(|s| s > 0))
+                .map(|d| known(&d["part_number"]).unwrap_or_else(|| "Unknown".to_string()))
+                .collect()
+        },
+    },
+    Dimension {
+        title: "Processors",
+        unit: Some("Sockets"),
+        values: |m| {
+            list(&m["processors"])
+                .iter()
+                .filter(|p| p["status"] != "Unpopulated")
+                .map(|p| known(&p["version"]).unwrap_or_else(|| "Unknown".to_string()))
+                .collect()
+        },
+    },
+];
+
+#[derive(Debug)]
+struct Group {
+    value: String,
+    hosts: Vec<usize>,
+    devices: usize,
+}
+
+#[derive(Debug)]
+pub struct Fleet {
+    hosts: Vec<Host>,
+    // Groups of every dimension, largest first.
+    groups: Vec<Vec<Group>>,
+    focused: usize,
+    selected: usize,
+    // The group being drilled into and the selected host in it.
+    drill: Option<(usize, usize)>,
+    // Hosts open with identifying fields masked, as with --redact.
+    pub redact: bool,
+    // Why the last host picked could not be opened, until the next key.
+    pub error: Option<String>,
+}
+
+impl Fleet {
+    /// Loads every table in `dir`. Files that aren't tables are reported on
+    /// stderr and skipped.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let mut paths: Vec<_> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+
+        let mut hosts = Vec::new();
+        for path in paths {
+            let loaded = table::read_file(&path)
+                .and_then(|table| DMI::from_table(&table).map(|dmi| (table, dmi.model())));
+            match loaded {
+                Ok((table, model)) => hosts.push(Host {
+                    name: path
+                        .file_stem()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string(),
+                    table,
+                    model,
+                }),
+                Err(e) => eprintln!("Skipping {}: {e}", path.display()),
+            }
+        }
+        if hosts.is_empty() {
+            bail!("No DMI table found in {}", dir.display());
+        }
+
+        let groups = DIMENSIONS
+            .iter()
+            .map(|dimension| {
+                let mut groups: Vec<Group> = Vec::new();
+                for (i, host) in hosts.iter().enumerate() {
+                    for value in (dimension.values)(&host.model) {
+                        let group = match groups.iter().position(|g| g.value == value) {
+                            Some(g) => &mut groups[g],
+                            None => {
+                                groups.push(Group {
+                                    value,
+                                    hosts: Vec::new(),
+                                    devices: 0,
+                                });
+                                groups.last_mut().unwrap()
+                            }
+                        };
+                        group.devices += 1;
+                        if group.hosts.last() != Some(&i) {
+                            group.hosts.push(i);
+                        }
+                    }
+                }
+                groups.sort_by_key(|g| std::cmp::Reverse(g.hosts.len()));
+                groups
+            })
+            .collect();
+
+        Ok(Self {
+            hosts,
+            groups,
+            focused: 0,
+            selected: 0,
+            drill: None,
+            redact: false,
+            error: None,
+        })
+    }
+
+    /// Returns the table of the host picked with Enter, if any.
+    pub fn handle_key_events(&mut self, key_event: KeyEvent) -> Option<&[u8]> {
+        self.error = None;
+        match &mut self.drill {
+            Some((group, selected)) => {
+                let count = self.groups[self.focused][*group].hosts.len();
+                match key_event.code {
+                    KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1) % count,
+                    KeyCode::Up | KeyCode::Char('k') => *selected = (*selected + count - 1) % count,
+                    KeyCode::Esc => self.drill = None,
+                    KeyCode::Enter => {
+                        let host = self.groups[self.focused][*group].hosts[*selected];
+                        return Some(&self.hosts[host].table);
+                    }
+                    _ => {}
+                }
+            }
+            None => {
+                let count = self.groups[self.focused].len().max(1);
+                match key_event.code {
+                    KeyCode::Tab => {
+                        self.focused = (self.focused + 1) % DIMENSIONS.len();
+                        self.selected = 0;
+                    }
+                    KeyCode::BackTab => {
+                        self.focused = (self.focused + DIMENSIONS.len() - 1) % DIMENSIONS.len();
+                        self.selected = 0;
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        self.selected = (self.selected + 1) % count
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        self.selected = (self.selected + count - 1) % count;
+                    }
+                    KeyCode::Enter if !self.groups[self.focused].is_empty() => {
+                        self.drill = Some((self.selected, 0));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)])
+            .split(frame.area());
+        let (section_block, help_block) = (chunks[0], chunks[1]);
+
+        let mut title_spans: Vec<Span<'_>> = DIMENSIONS
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let label = format!("  {}  ", d.title);
+                if i == self.focused {
+                    Span::styled(label, Style::new().bold().reversed())
+                } else {
+                    Span::from(label).dim()
+                }
+            })
+            .collect();
+        title_spans.push(Span::from(format!("  {} hosts  ", self.hosts.len())).dim());
+
+        frame.render_widget(
+            Block::default()
+                .title(Line::from(title_spans))
+                .title_alignment(Alignment::Left)
+                .padding(Padding::top(1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+            section_block,
+        );
+
+        let help = match self.drill {
+            Some(_) => "↑↓ : Cycle   ⏎ : Open host   Esc : Back",
+            None => "⇆ : Sections   ↑↓ : Cycle   ⏎ : Hosts",
+        };
+        match &self.error {
+            Some(error) => frame.render_widget(
+                Line::from(format!("Cannot open host: {error}"))
+                    .centered()
+                    .red(),
+                help_block,
+            ),
+            None => frame.render_widget(Line::from(help).centered().dim(), help_block),
+        }
+
+        let area = section_block.inner(Margin::new(4, 2));
+        match self.drill {
+            Some((group, selected)) => self.render_hosts(frame, area, group, selected),
+            None => self.render_groups(frame, area),
+        }
+    }
+
+    fn render_groups(&self, frame: &mut Frame, area: Rect) {
+        let dimension = &DIMENSIONS[self.focused];
+        let total = self.hosts.len();
+
+        let rows: Vec<Row<'_>> = self.groups[self.focused]
+            .iter()
+            .map(|g| {
+                let share = g.hosts.len() * 100 / total;
+                let mut cells = vec![
+                    Cell::from(g.value.clone()),
+                    Cell::from(g.hosts.len().to_string()),
+                    Cell::from(format!("{share:>3}% {}", "█".repeat(share / 5))),
+                ];
+                if dimension.unit.is_some() {
+                    cells.push(Cell::from(g.devices.to_string()));
+                }
+                Row::new(cells)
+            })
+            .collect();
+
+        let mut header = vec!["", "Hosts", "Share"];
+        let mut widths = vec![
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(26),
+        ];
+        if let Some(unit) = dimension.unit {
+            header.push(unit);
+            widths.push(Constraint::Length(8));
+        }
+
+        let table = Table::new(rows, widths)
+            .header(Row::new(header).bold().bottom_margin(1))
+            .column_spacing(2)
+            .row_highlight_style(Style::new().bold().reversed());
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, area, &mut state);
+    }
+
+    fn render_hosts(&self, frame: &mut Frame, area: Rect, group: usize, selected: usize) {
+        let group = &self.groups[self.focused][group];
+
+        let rows: Vec<Row<'_>> = group
+            .hosts
+            .iter()
+            .map(|&i| {
+                let host = &self.hosts[i];
+                Row::new(vec![
+                    Cell::from(host.name.clone()),
+                    Cell::from(joined(
+                        &host.model["system"],
+                        "manufacturer",
+                        "product_name",
+                    )),
+                    Cell::from(known(&host.model["system"]["serial_number"]).unwrap_or_default()),
+                    Cell::from(
+                        known(&host.model["firmware"]["firmware_version"]).unwrap_or_default(),
+                    ),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Percentage(25),
+            Constraint::Percentage(35),
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Host", "Product", "Serial Number", "Firmware"])
+                    .bold()
+                    .bottom_margin(1),
+            )
+            .column_spacing(2)
+            .row_highlight_style(Style::new().bold().reversed())
+            .block(
+                Block::new()
+                    .title(Line::from(format!(
+                        " {} · {} hosts ",
+                        group.value,
+                        group.hosts.len()
+                    )))
+                    .padding(Padding::bottom(1)),
+            );
+        let mut state = TableState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(table, area, &mut state);
+    }
+}
+
+// "Manufacturer Product" from two fields of a section, skipping unknown ones.
+fn joined(section: &Value, first: &str, second: &str) -> String {
+    let parts: Vec<String> = [first, second]
+        .iter()
+        .filter_map(|key| known(&section[*key]))
+        .collect();
+    if parts.is_empty() {
+        "Unknown".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
@@ -1,20 +1,38 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::App;
+use crate::{app::App, dmi::DMI};
 
 pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> Result<()> {
     match key_event.code {
-        KeyCode::Char('q') => {
+        KeyCode::Char('q') if !app.is_editing() => {
             app.quit();
         }
 
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.quit();
+        KeyCode::Char('c') | KeyCode::Char('C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit();
+        }
+        // Back from a host to the fleet overview.
+        KeyCode::Esc if app.fleet.is_some() && app.dmi.is_some() && !app.is_editing() => {
+            app.dmi = None;
+        }
+        _ => {
+            if let Some(compare) = &mut app.compare {
+                compare.handle_key_events(key_event);
+            } else if let Some(dmi) = &mut app.dmi {
+                dmi.handle_key_events(key_event);
+            } else if let Some(fleet) = &mut app.fleet
+                && let Some(table) = fleet.handle_key_events(key_event)
+            {
+                match DMI::from_table(table) {
+                    Ok(mut dmi) => {
+                        dmi.redact = fleet.redact;
+                        app.dmi = Some(dmi);
+                    }
+                    Err(e) => fleet.error = Some(e.to_string()),
+                }
             }
         }
-        _ => app.dmi.handle_key_events(key_event),
     }
     Ok(())
 }
@@ -1,5 +1,12 @@
 pub mod app;
+pub mod check;
+pub mod compare;
+pub mod diff;
 pub mod dmi;
 pub mod event;
+pub mod export;
+pub mod fleet;
 pub mod handlers;
+pub mod query;
+pub mod snapshot;
 pub mod tui;
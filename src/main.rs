@@ -1,30 +1,341 @@
-use std::io;
+use std::{fs, io, path::Path};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use dmitui::{
     app::App,
+    check::Policy,
+    compare::Compare,
+    diff,
+    dmi::{DMI, table},
     event::{Event, EventHandler},
+    export,
+    fleet::Fleet,
     handlers::handle_key_events,
+    query, snapshot,
     tui::Tui,
 };
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
-use clap::{Command, crate_description, crate_version};
+use clap::{Arg, ArgAction, ArgGroup, Command, crate_description, crate_version};
 
-fn main() -> Result<()> {
-    Command::new("dmitui")
+// Like diff(1): 1 means differences were found (or a policy rule failed),
+// 2 that something went wrong, so scripts and systemd units can tell them
+// apart.
+fn main() {
+    if let Err(error) = cli() {
+        eprintln!("Error: {error:?}");
+        std::process::exit(2);
+    }
+}
+
+fn cli() -> Result<()> {
+    let matches = Command::new("dmitui")
         .about(crate_description!())
         .version(crate_version!())
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .short('i')
+                .value_name("FILE")
+                .global(true)
+                .help("Read the table from a file (raw table or dmidecode --dump-bin) instead of this system"),
+        )
+        .arg(
+            Arg::new("memory")
+                .long("memory")
+                .value_name("FILE")
+                .help("Read the system event log area from a memory image instead of /dev/mem"),
+        )
+        .arg(
+            Arg::new("get")
+                .long("get")
+                .value_name("PATH")
+                .help("Print the values at a field path, e.g. 'memory.devices[*].part_number'"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("TEMPLATE")
+                .help("Print a template with {field.path} placeholders filled in"),
+        )
+        .arg(
+            Arg::new("redact")
+                .long("redact")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("output")
+                .help("Start the TUI with serial numbers, UUIDs and asset tags masked (toggle with r)"),
+        )
+        .arg(
+            Arg::new("hexdump")
+                .long("hexdump")
+                .action(ArgAction::SetTrue)
+                .help("Print every structure of the table undecoded, like dmidecode --dump"),
+        )
+        .arg(
+            Arg::new("redacted-dump")
+                .long("redacted-dump")
+                .value_name("FILE")
+                .help("Write the raw table with serial numbers, asset tags and UUIDs replaced"),
+        )
+        .arg(
+            Arg::new("report")
+                .long("report")
+                .value_name("FORMAT")
+                .value_parser(["markdown", "html"])
+                .help("Print a hardware report as a Markdown or self-contained HTML document"),
+        )
+        .arg(
+            Arg::new("prometheus")
+                .long("prometheus")
+                .action(ArgAction::SetTrue)
+                .help("Print metrics for the node_exporter textfile collector"),
+        )
+        .arg(
+            Arg::new("cyclonedx")
+                .long("cyclonedx")
+                .action(ArgAction::SetTrue)
+                .help("Print a CycloneDX hardware bill of materials as JSON"),
+        )
+        .arg(
+            Arg::new("redfish")
+                .long("redfish")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Print Redfish ComputerSystem, Processor, Memory and Chassis resources as JSON",
+                ),
+        )
+        .arg(
+            Arg::new("facts")
+                .long("facts")
+                .action(ArgAction::SetTrue)
+                .help("Print custom facts as JSON for Ansible facts.d or Facter"),
+        )
+        .arg(
+            Arg::new("sqlite")
+                .long("sqlite")
+                .value_name("DB")
+                .help("Append the inventory to normalized tables in a SQLite database"),
+        )
+        .arg(
+            Arg::new("lshw")
+                .long("lshw")
+                .value_name("FORMAT")
+                .value_parser(["json", "xml"])
+                .help("Print the device tree in the node format of lshw -json or lshw -xml"),
+        )
+        .arg(
+            Arg::new("compare")
+                .long("compare")
+                .value_name("FILE")
+                .conflicts_with("output")
+                .help("Show the table side by side with another one saved to a file"),
+        )
+        .group(ArgGroup::new("output").args([
+            "get",
+            "format",
+            "hexdump",
+            "redacted-dump",
+            "report",
+            "prometheus",
+            "cyclonedx",
+            "redfish",
+            "facts",
+            "sqlite",
+            "lshw",
+        ]))
+        .subcommand(
+            Command::new("snapshot")
+                .about("Save the decoded table under the state directory")
+                .arg(state_dir()),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Compare two snapshots, or a snapshot with the current table; exits 1 on changes, 2 on errors")
+                .arg(Arg::new("old").value_name("OLD").help("Snapshot to compare from [default: the latest one]"))
+                .arg(Arg::new("new").value_name("NEW").help("Snapshot to compare to [default: the current table]"))
+                .arg(state_dir()),
+        )
+        .subcommand(
+            Command::new("check")
+                .about("Check the table against a hardware policy; exits 1 when a rule fails, 2 on errors")
+                .arg(
+                    Arg::new("policy")
+                        .long("policy")
+                        .value_name("FILE")
+                        .required(true)
+                        .help("TOML file of [[rule]] tables"),
+                ),
+        )
+        .subcommand(
+            Command::new("fleet")
+                .about("Browse a directory of saved tables, grouped by product, firmware, DIMMs and processors")
+                .arg(Arg::new("dir").value_name("DIR").required(true).help("Directory of raw tables or dmidecode --dump-bin files")),
+        )
         .get_matches();
 
-    if unsafe { libc::geteuid() } != 0 {
-        eprintln!("dmitui must be run as root");
-        std::process::exit(1);
+    let input = matches.get_one::<String>("input");
+
+    match matches.subcommand() {
+        Some(("snapshot", args)) => {
+            let model = DMI::from_table(&read_table(input)?)?.model();
+            let dir = args.get_one::<String>("state-dir").unwrap();
+            let path = snapshot::save(&model, Path::new(dir))?;
+            println!("{}", path.display());
+            return Ok(());
+        }
+        Some(("diff", args)) => {
+            let dir = Path::new(args.get_one::<String>("state-dir").unwrap());
+            let old = match args.get_one::<String>("old") {
+                Some(path) => snapshot::load(Path::new(path))?,
+                None => snapshot::load(&snapshot::latest(dir)?)?,
+            };
+            let new = match args.get_one::<String>("new") {
+                Some(path) => snapshot::load(Path::new(path))?,
+                None => DMI::from_table(&read_table(input)?)?.model(),
+            };
+
+            let changes = diff::diff(&old, &new);
+            for change in &changes {
+                println!("{change}");
+            }
+            if !changes.is_empty() {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(("check", args)) => {
+            let policy = Policy::load(Path::new(args.get_one::<String>("policy").unwrap()))?;
+            let model = DMI::from_table(&read_table(input)?)?.model();
+
+            let outcomes = policy.check(&model);
+            for outcome in &outcomes {
+                println!("{outcome}");
+            }
+            let failed = outcomes.iter().filter(|o| o.failure.is_some()).count();
+            println!(
+                "\n{} of {} rules passed",
+                outcomes.len() - failed,
+                outcomes.len()
+            );
+            if failed > 0 {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(("fleet", args)) => {
+            let mut fleet = Fleet::load(Path::new(args.get_one::<String>("dir").unwrap()))?;
+            fleet.redact = matches.get_flag("redact");
+            return run(App::with_fleet(fleet));
+        }
+        _ => {}
+    }
+
+    let table = read_table(input)?;
+
+    if matches.get_flag("hexdump") {
+        let structures = table::parse(&table)?;
+        print!("{}", export::hexdump::render(&structures));
+        return Ok(());
+    }
+
+    if let Some(path) = matches.get_one::<String>("redacted-dump") {
+        let structures = table::parse(&table)?;
+        fs::write(path, export::redact::render(&structures))?;
+        return Ok(());
+    }
+
+    let mut dmi = DMI::from_table(&table)?;
+    // Before the live-only data is added, from sysfs and the event log: the
+    // other table has none, so every item of it would show as a difference.
+    let mut compare = match matches.get_one::<String>("compare") {
+        Some(path) => {
+            let other = DMI::from_table(&table::read_file(Path::new(path))?)?;
+            let name = |path: &str| {
+                Path::new(path)
+                    .file_name()
+                    .map_or(path.to_string(), |n| n.to_string_lossy().to_string())
+            };
+            Some(Compare::new(
+                input.map_or("This system".to_string(), |p| name(p)),
+                &dmi.model(),
+                name(path),
+                &other.model(),
+            ))
+        }
+        None => None,
+    };
+
+    if input.is_none() {
+        dmi.link_sysfs();
+    }
+    let memory = matches.get_one::<String>("memory").map(Path::new);
+    if input.is_none() || memory.is_some() {
+        dmi.read_event_log(memory);
+    }
+
+    if let Some(path) = matches.get_one::<String>("get") {
+        let model = dmi.model();
+        for value in query::get(&model, path)? {
+            println!("{}", query::to_text(value));
+        }
+        return Ok(());
     }
 
-    let mut app = App::new()?;
+    if let Some(template) = matches.get_one::<String>("format") {
+        println!("{}", query::format(&dmi.model(), template)?);
+        return Ok(());
+    }
 
+    if let Some(format) = matches.get_one::<String>("report") {
+        print!(
+            "{}",
+            export::report::render(&dmi.model(), export::report::Format::from(format.as_str()))
+        );
+        return Ok(());
+    }
+
+    if matches.get_flag("prometheus") {
+        print!("{}", export::prometheus::render(&dmi.model()));
+        return Ok(());
+    }
+
+    if matches.get_flag("cyclonedx") {
+        println!("{}", export::cyclonedx::render(&dmi.model()));
+        return Ok(());
+    }
+
+    if matches.get_flag("redfish") {
+        println!("{}", export::redfish::render(&dmi.model()));
+        return Ok(());
+    }
+
+    if matches.get_flag("facts") {
+        println!("{}", export::facts::render(&dmi.model()));
+        return Ok(());
+    }
+
+    if let Some(path) = matches.get_one::<String>("sqlite") {
+        export::sqlite::write(&dmi.model(), Path::new(path))?;
+        return Ok(());
+    }
+
+    if let Some(format) = matches.get_one::<String>("lshw") {
+        print!(
+            "{}",
+            export::lshw::render(&dmi.model(), export::lshw::Format::from(format.as_str()))
+        );
+        return Ok(());
+    }
+
+    dmi.redact = matches.get_flag("redact");
+    if let Some(compare) = &mut compare {
+        compare.redact = dmi.redact;
+    }
+    run(App::new(dmi, compare))
+}
+
+fn run(mut app: App) -> Result<()> {
     let backend = CrosstermBackend::new(io::stdout());
     let terminal = Terminal::new(backend)?;
     let events = EventHandler::new(250);
@@ -42,3 +353,24 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+fn state_dir() -> Arg {
+    Arg::new("state-dir")
+        .long("state-dir")
+        .value_name("DIR")
+        .default_value(snapshot::STATE_DIR)
+        .help("Directory holding the snapshots")
+}
+
+// The table from `--input`, or from the running system, which needs root.
+fn read_table(input: Option<&String>) -> Result<Vec<u8>> {
+    match input {
+        Some(path) => table::read_file(Path::new(path)),
+        None => {
+            if unsafe { libc::geteuid() } != 0 {
+                bail!("dmitui must be run as root");
+            }
+            table::read()
+        }
+    }
+}
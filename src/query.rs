@@ -0,0 +1,145 @@
+// Field path queries over the decoded model.
+//
+// A path is a dot separated list of field names, each optionally followed by
+// one or more `[N]` (index) or `[*]` (every element) selectors:
+//
+//   system.manufacturer
+//   processors[0].core_count
+//   memory.devices[*].part_number
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+enum Step {
+    Field(String),
+    Index(usize),
+    All,
+}
+
+fn parse(path: &str) -> Result<Vec<Step>> {
+    let mut steps = Vec::new();
+
+    for segment in path.split('.') {
+        let (name, mut selectors) = match segment.find('[') {
+            Some(i) => (&segment[..i], &segment[i..]),
+            None => (segment, ""),
+        };
+
+        if name.is_empty() {
+            bail!("Invalid path `{path}`: empty field name");
+        }
+        steps.push(Step::Field(name.to_string()));
+
+        while !selectors.is_empty() {
+            let Some(end) = selectors.find(']') else {
+                bail!("Invalid path `{path}`: missing `]`");
+            };
+            let selector = &selectors[1..end];
+            if selector == "*" {
+                steps.push(Step::All);
+            } else {
+                let index = selector
+                    .parse::<usize>()
+                    .map_err(|_| anyhow!("Invalid path `{path}`: bad index `{selector}`"))?;
+                steps.push(Step::Index(index));
+            }
+
+            selectors = &selectors[end + 1..];
+            if !selectors.is_empty() && !selectors.starts_with('[') {
+                bail!("Invalid path `{path}`: unexpected `{selectors}`");
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+/// Every value matched by `path`. Absent sections (null) yield no values.
+pub fn get<'a>(model: &'a Value, path: &str) -> Result<Vec<&'a Value>> {
+    let mut current = vec![model];
+
+    for step in parse(path)? {
+        let mut next = Vec::new();
+        for value in current {
+            match (&step, value) {
+                (_, Value::Null) => {}
+                (Step::Field(name), Value::Object(map)) => match map.get(name) {
+                    Some(v) => next.push(v),
+                    None => bail!("Unknown field `{name}` in `{path}`"),
+                },
+                (Step::Index(i), Value::Array(list)) => match list.get(*i) {
+                    Some(v) => next.push(v),
+                    None => bail!("Index {i} out of range in `{path}`"),
+                },
+                (Step::All, Value::Array(list)) => next.extend(list.iter()),
+                (Step::Field(name), _) => bail!("Cannot read field `{name}` in `{path}`"),
+                (_, _) => bail!("Cannot index a non-list value in `{path}`"),
+            }
+        }
+        current = next;
+    }
+
+    Ok(current)
+}
+
+/// Plain text form of a value: strings unquoted, null empty, the rest as JSON.
+pub fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+/// Field name as a title: `serial_number` becomes "Serial Number".
+pub fn humanize(key: &str) -> String {
+    key.split('_')
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Expand every `{path}` placeholder in `template`. `{{` and `}}` produce
+/// literal braces; a path matching several values is joined with ", ".
+pub fn format(model: &Value, template: &str) -> Result<String> {
+    let mut output = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let mut path = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => path.push(c),
+                        None => bail!("Unmatched `{{` in template"),
+                    }
+                }
+                let values: Vec<String> =
+                    get(model, path.trim())?.into_iter().map(to_text).collect();
+                output.push_str(&values.join(", "));
+            }
+            '}' => bail!("Unmatched `}}` in template"),
+            c => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,75 @@
+// Decoded models saved under a state directory, one JSON file per snapshot.
+// File names are UTC timestamps, so sorting them by name sorts them by age.
+// Snapshots taken within the same second get a zero-padded counter, `_002`
+// onwards, which sorts after the first one and in order.
+
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Value, json};
+
+use crate::export::timestamp;
+
+pub const STATE_DIR: &str = "/var/lib/dmitui";
+
+pub fn save(model: &Value, dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(dir).with_context(|| format!("{}", dir.display()))?;
+
+    let taken_at = timestamp();
+    let snapshot = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "taken_at": taken_at,
+        "model": model,
+    });
+    let text = serde_json::to_string_pretty(&snapshot)?;
+
+    // Never overwrite an earlier snapshot.
+    let mut path = dir.join(format!("{taken_at}.json"));
+    let mut n = 1;
+    loop {
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(text.as_bytes())
+                    .with_context(|| format!("{}", path.display()))?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                n += 1;
+                path = dir.join(format!("{taken_at}_{n:03}.json"));
+            }
+            Err(e) => return Err(e).with_context(|| format!("{}", path.display())),
+        }
+    }
+}
+
+pub fn load(path: &Path) -> Result<Value> {
+    let text = fs::read_to_string(path).with_context(|| format!("{}", path.display()))?;
+    let mut snapshot: Value =
+        serde_json::from_str(&text).with_context(|| format!("{}", path.display()))?;
+
+    match snapshot.get_mut("model") {
+        Some(model) => Ok(model.take()),
+        None => Err(anyhow!("{}: not a dmitui snapshot", path.display())),
+    }
+}
+
+pub fn latest(dir: &Path) -> Result<PathBuf> {
+    let mut snapshots: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("{}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    snapshots.sort();
+
+    snapshots
+        .pop()
+        .ok_or_else(|| anyhow!("No snapshot found in {}", dir.display()))
+}